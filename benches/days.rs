@@ -0,0 +1,36 @@
+use advent_2022::{days, generate};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn bench_days(c: &mut Criterion) {
+    for puzzle in days::all() {
+        let raw = advent_2022::read_input(puzzle.day);
+        let parsed = puzzle.parse(&raw).unwrap();
+
+        let mut group = c.benchmark_group(format!("day_{:02}", puzzle.day));
+        group.bench_function("parse", |b| b.iter(|| puzzle.parse(black_box(&raw))));
+        group.bench_function("part1", |b| b.iter(|| black_box(&parsed).part1()));
+        group.bench_function("part2", |b| b.iter(|| black_box(&parsed).part2()));
+        group.finish();
+    }
+}
+
+/// Parses and solves generated inputs of growing size to show how each day scales.
+fn bench_scaling(c: &mut Criterion) {
+    for puzzle in days::all() {
+        let mut group = c.benchmark_group(format!("day_{:02}_scaling", puzzle.day));
+        group.sample_size(10);
+        for size in [10, 100, 1000] {
+            let raw = generate::generate(puzzle.day, size, 0).unwrap();
+            group.bench_with_input(BenchmarkId::from_parameter(size), &raw, |b, raw| {
+                b.iter(|| {
+                    let parsed = puzzle.parse(black_box(raw)).unwrap();
+                    (parsed.part1(), parsed.part2())
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days, bench_scaling);
+criterion_main!(benches);
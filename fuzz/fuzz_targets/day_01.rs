@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_2022::{days::day_01::Day01, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(err) = Day01::parse(input) {
+        err.render(input);
+    }
+});
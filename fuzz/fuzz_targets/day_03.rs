@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_2022::{days::day_03::Day03, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(err) = Day03::parse(input) {
+        err.render(input);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_2022::{days::day_06::Day06, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Err(err) = Day06::parse(input) {
+        err.render(input);
+    }
+});
@@ -0,0 +1,130 @@
+use std::{collections::BTreeMap, fmt, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{toml_file, Answer};
+
+/// Known answers, keyed by day and then by input file, as stored in `answers.toml`:
+///
+/// ```toml
+/// [day_05."data/day_05.txt"]
+/// part1 = "HBTMTBSDC"
+/// part2 = "PQTJRSHWS"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Parts>>);
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Parts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+impl Parts {
+    fn part(&self, part: u8) -> &Option<Answer> {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+
+    fn part_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    New,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {})", expected),
+            Verdict::New => write!(f, "NEW"),
+        }
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+impl Answers {
+    /// Loads the registry, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        toml_file::load(path)
+    }
+
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        toml_file::parse(contents)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        toml_file::save(self, path)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&Answer> {
+        self.0.get(&day_key(day))?.get(input)?.part(part).as_ref()
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, input, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::New,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: u8, answer: Answer) {
+        *self
+            .0
+            .entry(day_key(day))
+            .or_default()
+            .entry(input.to_string())
+            .or_default()
+            .part_mut(part) = Some(answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_and_record() {
+        let mut answers = Answers::default();
+        let answer = Answer::Text("CMZ".to_string());
+        assert_eq!(answers.check(5, "a.txt", 1, &answer), Verdict::New);
+
+        answers.record(5, "a.txt", 1, answer.clone());
+        assert_eq!(answers.check(5, "a.txt", 1, &answer), Verdict::Pass);
+        assert_eq!(answers.check(5, "a.txt", 2, &answer), Verdict::New);
+        assert_eq!(answers.check(5, "b.txt", 1, &answer), Verdict::New);
+        assert_eq!(
+            answers.check(5, "a.txt", 1, &Answer::Text("MCD".to_string())),
+            Verdict::Fail { expected: answer }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.record(1, "data/day_01.txt", 1, Answer::Number(74394));
+        answers.record(5, "data/day_05.txt", 2, Answer::Text("PQTJRSHWS".into()));
+        let contents = toml::to_string(&answers).unwrap();
+        assert!(contents.contains("[day_01.\"data/day_01.txt\"]\npart1 = 74394\n"));
+        assert_eq!(Answers::parse(&contents).unwrap(), answers);
+    }
+}
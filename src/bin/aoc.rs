@@ -0,0 +1,456 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_2022::{
+    answers::Answers,
+    client::Client,
+    days::{
+        self,
+        day_01::{Stats, StreamError, Totals},
+    },
+    fetch::{self, Fetched},
+    input::{self, Source},
+    runner::{self, Csv, Days, Json, Summary, Table},
+    scaffold,
+    submit::{self, History, Outcome},
+    watch::{self, Diff, Results, Snapshot},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+
+#[derive(Parser)]
+#[command(
+    about = "Run Advent of Code 2022 solutions",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate a new day from `template.rs`
+    NewDay {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Download a day's input into `data/` using the session in `AOC_SESSION`
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a part on the day's `data/` input and submit the answer
+    Submit {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Where previous submissions are recorded
+        #[arg(long, default_value = ".aoc-history.toml")]
+        history: PathBuf,
+    },
+    /// Print statistics about the elves' calorie totals in a day 1 input
+    Stats {
+        /// Read the inventories from this file instead of `data/`, or `-` for stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Re-run a day on its input and examples whenever they or its source change
+    Watch {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// How often to check for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day or days to run, e.g. `5`, `1-4` or `1,3,6-8`
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<Days>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every implemented day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Read the puzzle input from this file instead of `data/`, or `-` for stdin
+    #[arg(short, long, conflicts_with = "inline")]
+    input: Option<PathBuf>,
+
+    /// Use this string as the puzzle input
+    #[arg(long)]
+    inline: Option<String>,
+
+    /// Registry of known answers to check results against
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Save this run's answers to the registry
+    #[arg(long)]
+    record: bool,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Number of days to run at once; defaults to one per CPU
+    #[arg(short, long, default_value_t = 0)]
+    jobs: usize,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl RunArgs {
+    fn source(&self) -> Source {
+        match (&self.input, &self.inline) {
+            (Some(path), _) => Source::from_path(path.clone()),
+            (_, Some(input)) => Source::Inline(input.clone()),
+            _ => Source::Data,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::NewDay { day }) => new_day(day),
+        Some(Command::Fetch { day }) => fetch(day),
+        Some(Command::Submit { day, part, history }) => submit(day, part, &history),
+        Some(Command::Stats { input }) => stats(input),
+        Some(Command::Watch { day, interval }) => watch(day, Duration::from_millis(interval)),
+        None => run(cli.run),
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    match scaffold::new_day(Path::new("."), day) {
+        Ok(created) => {
+            for path in created {
+                println!("Created {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Failed to create day {}: {}", day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(day: u8) -> ExitCode {
+    let root = Path::new(".");
+    let result = match fetch::cached(root, day) {
+        Some(path) => Ok(Fetched::Cached(path)),
+        None => Client::from_env().and_then(|client| fetch::fetch(&client, root, day)),
+    };
+    match result {
+        Ok(Fetched::Cached(path)) => {
+            println!("{} already exists", path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Failed to fetch day {}: {}", day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(day: u8, part: u8, history_path: &Path) -> ExitCode {
+    let Some(puzzle) = days::get(day) else {
+        eprintln!("Day {} is not implemented", day);
+        return ExitCode::FAILURE;
+    };
+    let input = match Source::Data.read(day) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to read {}: {}", Source::Data.name(day), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let answer = match runner::run(&puzzle, &input, &[part]) {
+        Ok(mut rows) => match rows.remove(0).result {
+            Ok(answer) => answer,
+            Err(message) => {
+                eprintln!("Day {} part {} panicked: {}", day, part, message);
+                return ExitCode::FAILURE;
+            }
+        },
+        Err(err) => {
+            eprint!("{}", err.with_file(Source::Data.name(day)).render(&input));
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut history = match History::load(history_path) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Failed to load {}: {}", history_path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    let result = submit::submit(&client, &mut history, day, part, &answer, submit::now());
+    if let Err(err) = history.save(history_path) {
+        eprintln!("Failed to save {}: {}", history_path.display(), err);
+        return ExitCode::FAILURE;
+    }
+    match result {
+        Ok(attempt) => {
+            println!("{}: {}", attempt.outcome, attempt.message);
+            if attempt.outcome == Outcome::Right {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn stats(input: Option<PathBuf>) -> ExitCode {
+    let source = Source::from_path(input.unwrap_or_else(|| input::data_path(1)));
+    let name = source.name(1);
+    // Inventories are streamed rather than read into memory, so huge ones can be summarized.
+    let reader: Box<dyn BufRead> = match &source {
+        Source::File(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(err) => {
+                eprintln!("Failed to read {}: {}", name, err);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => Box::new(io::stdin().lock()),
+    };
+
+    let elves = match Totals::new(reader).collect::<Result<Vec<_>, _>>() {
+        Ok(elves) => elves,
+        Err(StreamError::Parse(err)) => {
+            eprintln!("error: {}", err.with_file(name));
+            return ExitCode::FAILURE;
+        }
+        Err(StreamError::Io(err)) => {
+            eprintln!("Failed to read {}: {}", name, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    match Stats::new(&elves) {
+        Some(stats) => {
+            print!("{}", stats);
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("{} has no elves", name);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn watch(day: u8, interval: Duration) -> ExitCode {
+    let root = Path::new(".");
+    let mut snapshot = Snapshot::default();
+    let mut previous = Results::default();
+    loop {
+        let paths = match watch::watched_paths(root, day) {
+            Ok(paths) => paths,
+            Err(err) => {
+                eprintln!("Failed to list the files for day {}: {}", day, err);
+                return ExitCode::FAILURE;
+            }
+        };
+        if !root.join(&paths[0]).exists() {
+            eprintln!("{} does not exist", paths[0].display());
+            return ExitCode::FAILURE;
+        }
+
+        let current = Snapshot::take(root, &paths);
+        let changed: Vec<String> = current
+            .changed(&snapshot)
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        if !changed.is_empty() {
+            if snapshot != Snapshot::default() {
+                println!("\nChanged: {}", changed.join(", "));
+            }
+            match watch::build(root) {
+                None => println!("Build failed; keeping previous results"),
+                Some(binary) => {
+                    // The first path is the source; everything after it is an input to run.
+                    let mut results = Results::default();
+                    for input in paths[1..].iter() {
+                        let Some(json) = watch::run(&binary, day, input) else {
+                            continue;
+                        };
+                        let name = input.display().to_string();
+                        if let Err(err) = results.extend_from_json(&name, &json) {
+                            eprintln!("Unexpected output for {}: {}", name, err);
+                        }
+                    }
+                    print!("{}", Diff(&previous, &results));
+                    if !results.is_empty() {
+                        previous = results;
+                    }
+                }
+            }
+            snapshot = current;
+        }
+        thread::sleep(interval);
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let source = args.source();
+
+    let puzzles = match args.day {
+        Some(Days(selected)) => {
+            let mut puzzles = Vec::new();
+            for day in selected {
+                match days::get(day) {
+                    Some(puzzle) => puzzles.push(puzzle),
+                    None => {
+                        eprintln!("Day {} is not implemented", day);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            puzzles
+        }
+        None => days::all(),
+    };
+    if source != Source::Data && puzzles.len() != 1 {
+        eprintln!("A custom input can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let mut answers = match Answers::load(&args.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Failed to load {}: {}", args.answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let pool = match rayon::ThreadPoolBuilder::new()
+        .num_threads(args.jobs)
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(err) => {
+            eprintln!("Failed to start thread pool: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Days run concurrently, but results are collected in day order so output is deterministic.
+    let start = Instant::now();
+    let results: Vec<_> = pool.install(|| {
+        puzzles
+            .par_iter()
+            .map(|puzzle| {
+                let input = source.read(puzzle.day)?;
+                let result = runner::run(puzzle, &input, &parts);
+                Ok((input, result))
+            })
+            .collect()
+    });
+    let wall = start.elapsed();
+
+    let mut rows = Vec::new();
+    let mut failed = false;
+    for (puzzle, result) in puzzles.iter().zip(results) {
+        let (input, result) = match result {
+            Ok(result) => result,
+            Err::<_, std::io::Error>(err) => {
+                eprintln!("Failed to read {}: {}", source.name(puzzle.day), err);
+                return ExitCode::FAILURE;
+            }
+        };
+        match result {
+            Ok(mut day_rows) => {
+                // Only inputs read from a file can be looked up in the registry.
+                if let Source::Data | Source::File(_) = source {
+                    let name = source.name(puzzle.day);
+                    for row in day_rows.iter_mut() {
+                        if let Ok(answer) = &row.result {
+                            row.verdict = Some(answers.check(row.day, &name, row.part, answer));
+                            if args.record {
+                                answers.record(row.day, &name, row.part, answer.clone());
+                            }
+                        }
+                    }
+                }
+                rows.extend(day_rows);
+            }
+            Err(err) => {
+                eprint!("{}", err.with_file(source.name(puzzle.day)).render(&input));
+                failed = true;
+            }
+        }
+    }
+
+    match args.format {
+        Format::Table if !rows.is_empty() => {
+            print!("{}", Table(&rows));
+            if puzzles.len() > 1 {
+                print!("\n{}", Summary(&rows, wall));
+            }
+        }
+        Format::Table => {}
+        Format::Json => print!("{}", Json(&rows)),
+        Format::Csv => print!("{}", Csv(&rows)),
+    }
+
+    if args.record {
+        if let Err(err) = answers.save(&args.answers) {
+            eprintln!("Failed to save {}: {}", args.answers.display(), err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if failed || rows.iter().any(|row| row.failed()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,177 @@
+use std::{env, fmt, fs, io, path::Path, time::Duration};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/rstratton/advent-2022";
+
+/// An authenticated connection to the Advent of Code site.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingSession => write!(
+                f,
+                "no session token; set AOC_SESSION or write it to {}",
+                SESSION_FILE
+            ),
+            Error::Status(status, body) => write!(f, "server returned {}: {}", status, body.trim()),
+            Error::Transport(message) => write!(f, "{}", message),
+            Error::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<ureq::Error> for Error {
+    fn from(err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(status, response) => {
+                Error::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => Error::Transport(transport.to_string()),
+        }
+    }
+}
+
+const SESSION_FILE: &str = ".aoc-session";
+
+/// Reads the session cookie from `AOC_SESSION`, falling back to `.aoc-session` if the variable
+/// is unset or blank.
+pub fn session() -> Result<String, Error> {
+    session_from(env::var("AOC_SESSION").ok(), Path::new(SESSION_FILE))
+}
+
+fn session_from(var: Option<String>, file: &Path) -> Result<String, Error> {
+    if let Some(session) = var.filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_string());
+    }
+    match fs::read_to_string(file) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(Error::MissingSession),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(Error::MissingSession),
+        Err(err) => Err(err.into()),
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// A client for the real site, or for `AOC_BASE_URL` when set.
+    pub fn from_env() -> Result<Self, Error> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(&base_url, &session()?))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/2022/day/{}{}", self.base_url, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, day: u8) -> Result<String, Error> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Posts an answer and returns the HTML page the site responds with.
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String, Error> {
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        Ok(response.into_string()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{temp_dir, MockServer};
+
+    #[test]
+    fn test_input() {
+        let server = MockServer::start(vec![(200, "1\n2\n")]);
+        let client = Client::new(&server.url, "abc123");
+        assert_eq!(client.input(3).unwrap(), "1\n2\n");
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2022/day/3/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_answer() {
+        let server = MockServer::start(vec![(200, "<article><p>Ok</p></article>")]);
+        let client = Client::new(&server.url, "abc123");
+        assert_eq!(
+            client.answer(5, 2, "MCD").unwrap(),
+            "<article><p>Ok</p></article>"
+        );
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=MCD"));
+    }
+
+    #[test]
+    fn test_error_status() {
+        let server = MockServer::start(vec![(400, "Please log in")]);
+        let client = Client::new(&server.url, "expired");
+        match client.input(3) {
+            Err(Error::Status(400, body)) => assert_eq!(body, "Please log in"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_session() {
+        let root = temp_dir("session");
+        let file = root.join(".aoc-session");
+        let session = |var: Option<&str>| session_from(var.map(String::from), &file);
+
+        assert_eq!(session(Some(" abc\n")).unwrap(), "abc");
+        assert!(matches!(session(Some(" ")), Err(Error::MissingSession)));
+        fs::write(&file, "def\n").unwrap();
+        assert_eq!(session(Some("")).unwrap(), "def");
+        assert_eq!(session(None).unwrap(), "def");
+        fs::write(&file, "\n").unwrap();
+        assert!(matches!(session(Some("\t")), Err(Error::MissingSession)));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
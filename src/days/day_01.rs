@@ -0,0 +1,662 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BinaryHeap},
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{parse, ParseError, Solution};
+
+pub struct Day01;
+
+/// An elf among the top k, with its position in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranked<'a> {
+    pub index: usize,
+    pub total: u64,
+    pub items: &'a [u64],
+}
+
+/// An elf whose calories add up to more than fits in a `u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub index: usize,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the calories of elf {} overflow a u64", self.index + 1)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// The sum of an elf's items, or `None` if it overflows.
+pub fn total(items: &[u64]) -> Option<u64> {
+    items
+        .iter()
+        .try_fold(0u64, |total, &calories| total.checked_add(calories))
+}
+
+/// The `k` elves carrying the most calories, most first. Ties go to the elf listed first.
+pub fn top_k(inventories: &[Vec<u64>], k: usize) -> Result<Vec<Ranked<'_>>, Overflow> {
+    let mut top = TopK::new(k);
+    for (index, items) in inventories.iter().enumerate() {
+        let total = total(items).ok_or(Overflow { index })?;
+        top.push(index, total, items.as_slice());
+    }
+    Ok(top
+        .into_sorted()
+        .into_iter()
+        .map(|(index, total, items)| Ranked {
+            index,
+            total,
+            items,
+        })
+        .collect())
+}
+
+/// Keeps the `k` largest totals seen so far, along with a value for each, in a min-heap so
+/// that each push is O(log k).
+pub struct TopK<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<Entry<T>>>,
+}
+
+struct Entry<T> {
+    index: usize,
+    total: u64,
+    value: T,
+}
+
+impl<T> Entry<T> {
+    /// Larger totals rank higher, then earlier elves.
+    fn key(&self) -> (u64, Reverse<usize>) {
+        (self.total, Reverse(self.index))
+    }
+}
+
+impl<T> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T> Eq for Entry<T> {}
+
+impl<T> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<T> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: u64, value: T) {
+        self.heap.push(Reverse(Entry {
+            index,
+            total,
+            value,
+        }));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The kept `(index, total, value)` entries, highest ranked first.
+    pub fn into_sorted(self) -> Vec<(usize, u64, T)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(entry)| (entry.index, entry.total, entry.value))
+            .collect()
+    }
+}
+
+/// One elf's calorie total, as read by [`Totals`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfTotal {
+    pub index: usize,
+    pub total: u64,
+    pub items: usize,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+/// The calories on one line of the `elf`th elf's inventory. Surrounding whitespace is ignored.
+fn calorie_count(line: &str, line_number: usize, elf: usize) -> Result<u64, ParseError> {
+    parse::number(line, line_number, 1, "a calorie count").map_err(|err| {
+        let expected = if line.trim().starts_with('-') {
+            "a calorie count of zero or more"
+        } else {
+            &err.expected
+        };
+        ParseError {
+            expected: format!("{} for elf {}", expected, elf),
+            ..err
+        }
+    })
+}
+
+fn overflow(line: usize, elf: usize) -> ParseError {
+    let expected = format!(
+        "a calorie count that keeps the total of elf {} within a u64",
+        elf
+    );
+    ParseError::new(line, 1, expected)
+}
+
+/// Reads inventories a line at a time, yielding each elf's total as soon as its last item
+/// has been read. Elves are split up the same way as by [`Day01::parse`], but only the current
+/// line is ever held in memory. Iteration stops after the first error.
+pub struct Totals<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    emitted: usize,
+    total: u64,
+    items: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Totals<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            emitted: 0,
+            total: 0,
+            items: 0,
+            done: false,
+        }
+    }
+
+    fn emit(&mut self) -> ElfTotal {
+        let elf = ElfTotal {
+            index: self.emitted,
+            total: std::mem::take(&mut self.total),
+            items: std::mem::take(&mut self.items),
+        };
+        self.emitted += 1;
+        elf
+    }
+
+    fn fail(&mut self, err: impl Into<StreamError>) -> Option<Result<ElfTotal, StreamError>> {
+        self.done = true;
+        Some(Err(err.into()))
+    }
+}
+
+impl<R: BufRead> Iterator for Totals<R> {
+    type Item = Result<ElfTotal, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return (self.items > 0).then(|| Ok(self.emit()));
+                }
+                Ok(_) => self.line_number += 1,
+                Err(err) => return self.fail(err),
+            }
+
+            let elf = self.emitted + 1;
+            if parse::is_blank(&self.line) {
+                if self.items > 0 {
+                    return Some(Ok(self.emit()));
+                }
+                continue;
+            }
+            match calorie_count(&self.line, self.line_number, elf) {
+                Ok(calories) => match self.total.checked_add(calories) {
+                    Some(total) => {
+                        self.total = total;
+                        self.items += 1;
+                    }
+                    None => return self.fail(overflow(self.line_number, elf)),
+                },
+                Err(err) => return self.fail(err),
+            }
+        }
+        None
+    }
+}
+
+/// The top `k` elves in `reader`, most first, in memory proportional to `k` rather than to
+/// the size of the input.
+pub fn top_k_streaming<R: BufRead>(reader: R, k: usize) -> Result<Vec<ElfTotal>, StreamError> {
+    let mut top = TopK::new(k);
+    for elf in Totals::new(reader) {
+        let elf = elf?;
+        top.push(elf.index, elf.total, elf.items);
+    }
+    Ok(top
+        .into_sorted()
+        .into_iter()
+        .map(|(index, total, items)| ElfTotal {
+            index,
+            total,
+            items,
+        })
+        .collect())
+}
+
+/// Every elf's total, in input order.
+pub fn elf_totals(inventories: &[Vec<u64>]) -> Result<Vec<ElfTotal>, Overflow> {
+    inventories
+        .iter()
+        .enumerate()
+        .map(|(index, items)| {
+            Ok(ElfTotal {
+                index,
+                total: total(items).ok_or(Overflow { index })?,
+                items: items.len(),
+            })
+        })
+        .collect()
+}
+
+const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+/// Summary statistics over the elves' calorie totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    /// Nearest-rank percentiles as `(p, total)` pairs.
+    pub percentiles: Vec<(u8, u64)>,
+    /// How many elves carry each number of items.
+    pub item_counts: BTreeMap<usize, usize>,
+}
+
+impl Stats {
+    /// `None` if there are no elves.
+    pub fn new(elves: &[ElfTotal]) -> Option<Self> {
+        let mut totals: Vec<u64> = elves.iter().map(|elf| elf.total).collect();
+        totals.sort_unstable();
+        let count = totals.len();
+        let (min, max) = (*totals.first()?, *totals.last()?);
+
+        let sum: u128 = totals.iter().map(|&total| total as u128).sum();
+        let median = match count % 2 {
+            1 => totals[count / 2] as f64,
+            _ => (totals[count / 2 - 1] as f64 + totals[count / 2] as f64) / 2.0,
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * count).div_ceil(100).max(1);
+                (p, totals[rank - 1])
+            })
+            .collect();
+
+        let mut item_counts = BTreeMap::new();
+        for elf in elves {
+            *item_counts.entry(elf.items).or_insert(0) += 1;
+        }
+
+        Some(Self {
+            count,
+            min,
+            max,
+            mean: sum as f64 / count as f64,
+            median,
+            percentiles,
+            item_counts,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Elves   {}", self.count)?;
+        writeln!(f, "Min     {}", self.min)?;
+        writeln!(f, "Max     {}", self.max)?;
+        writeln!(f, "Mean    {:.1}", self.mean)?;
+        writeln!(f, "Median  {:.1}", self.median)?;
+        for (p, total) in self.percentiles.iter() {
+            writeln!(f, "p{:<6} {}", p, total)?;
+        }
+
+        writeln!(f, "\nItems  Elves")?;
+        let most = self.item_counts.values().copied().max().unwrap_or(0);
+        for (items, elves) in self.item_counts.iter() {
+            let bar = "#".repeat((elves * 40).div_ceil(most));
+            writeln!(f, "{:>5}  {:>5}  {}", items, elves, bar)?;
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Vec<u64>>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    /// Elves are separated by one or more blank lines. A blank file has no elves.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::groups(input)
+            .into_iter()
+            .enumerate()
+            .map(|(idx, group)| {
+                let elf = idx + 1;
+                let mut total = 0u64;
+                group
+                    .into_iter()
+                    .map(|(line_number, line)| {
+                        let calories = calorie_count(line, line_number, elf)?;
+                        total = total
+                            .checked_add(calories)
+                            .ok_or_else(|| overflow(line_number, elf))?;
+                        Ok(calories)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Totals were checked for overflow while parsing.
+    fn part1(inventories: &Self::Input) -> u64 {
+        top_k(inventories, 1)
+            .unwrap()
+            .first()
+            .map_or(0, |elf| elf.total)
+    }
+
+    fn part2(inventories: &Self::Input) -> u64 {
+        top_k(inventories, 3)
+            .unwrap()
+            .iter()
+            .map(|elf| elf.total)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> Vec<Vec<u64>> {
+        Day01::parse(&crate::read_input(Day01::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day01::part1(&input()), 74394);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day01::part2(&input()), 212836);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day01::parse(&crate::read_example(Day01::DAY, 1)).unwrap();
+        assert_eq!(Day01::part1(&input), 24000);
+        assert_eq!(Day01::part2(&input), 45000);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day01::DAY)) {
+            let input = Day01::parse(&input).unwrap();
+            assert_eq!(Day01::part1(&input), 74394);
+            assert_eq!(Day01::part2(&input), 212836);
+        }
+    }
+
+    #[test]
+    fn test_top_k() {
+        let input = Day01::parse(&crate::read_example(Day01::DAY, 1)).unwrap();
+        let top = top_k(&input, 3).unwrap();
+        assert_eq!(
+            top[0],
+            Ranked {
+                index: 3,
+                total: 24000,
+                items: &[7000, 8000, 9000]
+            }
+        );
+        let ranking: Vec<(usize, u64)> = top.iter().map(|elf| (elf.index, elf.total)).collect();
+        assert_eq!(ranking, vec![(3, 24000), (2, 11000), (4, 10000)]);
+
+        assert_eq!(top_k(&input, 10).unwrap().len(), 5);
+        assert!(top_k(&input, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_top_k_ties() {
+        let inventories = vec![vec![5], vec![2, 3], vec![1], vec![4, 1]];
+        let indices: Vec<usize> = top_k(&inventories, 2)
+            .unwrap()
+            .iter()
+            .map(|elf| elf.index)
+            .collect();
+        assert_eq!(indices, vec![0, 1]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_top_k_matches_sort(
+            inventories in prop::collection::vec(prop::collection::vec(0u64..1000, 0..5), 0..40),
+            k in 0usize..10,
+        ) {
+            let mut expected: Vec<(usize, u64)> = inventories
+                .iter()
+                .enumerate()
+                .map(|(index, items)| (index, items.iter().sum()))
+                .collect();
+            expected.sort_by_key(|&(index, total)| (Reverse(total), index));
+            expected.truncate(k);
+
+            let actual: Vec<(usize, u64)> = top_k(&inventories, k)
+                .unwrap()
+                .iter()
+                .map(|elf| (elf.index, elf.total))
+                .collect();
+            prop_assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_parse() {
+        let mut inputs = vec![
+            crate::read_input(Day01::DAY),
+            "".to_string(),
+            "\n1\n\n\n\n2\n3\n\n\n".to_string(),
+        ];
+        inputs.extend((0..5).map(|seed| crate::generate::generate(1, 200, seed).unwrap()));
+
+        for input in inputs {
+            let inventories = Day01::parse(&input).unwrap();
+            let expected = elf_totals(&inventories).unwrap();
+            let actual: Vec<ElfTotal> = Totals::new(input.as_bytes())
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(actual, expected, "{:?}", input);
+
+            let top: Vec<(usize, u64)> = top_k_streaming(input.as_bytes(), 3)
+                .unwrap()
+                .iter()
+                .map(|elf| (elf.index, elf.total))
+                .collect();
+            let expected: Vec<(usize, u64)> = top_k(&inventories, 3)
+                .unwrap()
+                .iter()
+                .map(|elf| (elf.index, elf.total))
+                .collect();
+            assert_eq!(top, expected);
+        }
+    }
+
+    #[test]
+    fn test_streaming_errors() {
+        let mut totals = Totals::new("1000\r\n\r\n2000\r\nabc\r\n3000".as_bytes());
+        assert_eq!(totals.next().unwrap().unwrap().total, 1000);
+        match totals.next() {
+            Some(Err(StreamError::Parse(err))) => assert_eq!((err.line, err.column), (4, 1)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(totals.next().is_none());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = format!("1\n\n{}\n1", u64::MAX);
+        let err = Day01::parse(&input).err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+
+        let mut totals = Totals::new(input.as_bytes());
+        assert!(totals.next().unwrap().is_ok());
+        match totals.next() {
+            Some(Err(StreamError::Parse(err))) => assert_eq!((err.line, err.column), (4, 1)),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let inventories = vec![vec![1], vec![u64::MAX, 1]];
+        assert_eq!(top_k(&inventories, 1), Err(Overflow { index: 1 }));
+        assert_eq!(elf_totals(&inventories), Err(Overflow { index: 1 }));
+    }
+
+    #[test]
+    fn test_stats() {
+        let input = Day01::parse(&crate::read_example(Day01::DAY, 1)).unwrap();
+        let stats = Stats::new(&elf_totals(&input).unwrap()).unwrap();
+        assert_eq!((stats.count, stats.min, stats.max), (5, 4000, 24000));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(
+            stats.percentiles,
+            vec![
+                (10, 4000),
+                (25, 6000),
+                (75, 11000),
+                (90, 24000),
+                (99, 24000)
+            ]
+        );
+        assert_eq!(stats.item_counts, BTreeMap::from([(1, 2), (2, 1), (3, 2)]));
+        assert_eq!(
+            stats.to_string(),
+            "Elves   5\n\
+             Min     4000\n\
+             Max     24000\n\
+             Mean    11000.0\n\
+             Median  10000.0\n\
+             p10     4000\n\
+             p25     6000\n\
+             p75     11000\n\
+             p90     24000\n\
+             p99     24000\n\
+             \n\
+             Items  Elves\n\
+             \x20   1      2  ########################################\n\
+             \x20   2      1  ####################\n\
+             \x20   3      2  ########################################\n"
+        );
+
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_tolerant_parse() {
+        let input = "  \n\n 1000 \r\n2000\t\n\n\n\n3000\n   \n\n";
+        assert_eq!(
+            Day01::parse(input).unwrap(),
+            vec![vec![1000, 2000], vec![3000]]
+        );
+        let totals: Vec<u64> = Totals::new(input.as_bytes())
+            .map(|elf| elf.unwrap().total)
+            .collect();
+        assert_eq!(totals, vec![3000, 3000]);
+
+        for blank in ["", "\n", " \r\n\t\n"] {
+            let inventories = Day01::parse(blank).unwrap();
+            assert!(inventories.is_empty());
+            assert_eq!(
+                (Day01::part1(&inventories), Day01::part2(&inventories)),
+                (0, 0)
+            );
+            assert!(Totals::new(blank.as_bytes()).next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        for (input, line, column, expected) in [
+            (
+                "1000\n\n  -5",
+                3,
+                3,
+                "a calorie count of zero or more for elf 2",
+            ),
+            ("1000\n\n\n\n2000\nabc", 6, 1, "a calorie count for elf 2"),
+            ("\n\n1.5", 3, 1, "a calorie count for elf 1"),
+        ] {
+            let err = Day01::parse(input).err().unwrap();
+            assert_eq!(
+                (err.line, err.column, err.expected.as_str()),
+                (line, column, expected)
+            );
+
+            match Totals::new(input.as_bytes()).find_map(Result::err) {
+                Some(StreamError::Parse(streamed)) => assert_eq!(streamed, err),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day01::parse("1000\n2000\n\nabc").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+}
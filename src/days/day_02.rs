@@ -0,0 +1,342 @@
+use std::fmt;
+
+use crate::{parse, ParseError, Solution};
+
+pub struct Day02;
+
+/// A move, numbered from 0 in scoring order: move `n` is worth `n + 1` points. Moves come
+/// from the [`Game`] they are played in, so they are always in range for it.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Action(usize);
+
+impl Action {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl Outcome {
+    fn score(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesError {
+    UnknownMove(usize),
+    BeatsItself(usize),
+    /// Two moves that are each said to beat the other.
+    Contradiction(usize, usize),
+    /// Two distinct moves with no winner between them.
+    Undecided(usize, usize),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RulesError::UnknownMove(a) => write!(f, "move {} is not part of the game", a),
+            RulesError::BeatsItself(a) => write!(f, "move {} beats itself", a),
+            RulesError::Contradiction(a, b) => write!(f, "moves {} and {} beat each other", a, b),
+            RulesError::Undecided(a, b) => {
+                write!(f, "nothing decides between moves {} and {}", a, b)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+/// A game where every pair of distinct moves has exactly one winner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    /// `beats[a][b]` is whether move `a` beats move `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+impl Game {
+    /// A game of `moves` moves from `(winner, loser)` pairs, which must settle every pair of
+    /// distinct moves one way.
+    pub fn new(moves: usize, wins: &[(usize, usize)]) -> Result<Self, RulesError> {
+        let mut beats = vec![vec![false; moves]; moves];
+        for &(winner, loser) in wins {
+            if let Some(&unknown) = [winner, loser].iter().find(|&&m| m >= moves) {
+                return Err(RulesError::UnknownMove(unknown));
+            }
+            if winner == loser {
+                return Err(RulesError::BeatsItself(winner));
+            }
+            if beats[loser][winner] {
+                return Err(RulesError::Contradiction(winner, loser));
+            }
+            beats[winner][loser] = true;
+        }
+        let mut pairs = (0..moves).flat_map(|a| (a + 1..moves).map(move |b| (a, b)));
+        if let Some((a, b)) = pairs.find(|&(a, b)| !beats[a][b] && !beats[b][a]) {
+            return Err(RulesError::Undecided(a, b));
+        }
+        Ok(Game { beats })
+    }
+
+    /// The balanced game where each move beats the `(moves - 1) / 2` moves before it,
+    /// wrapping around. Only an odd number of moves can be balanced.
+    pub fn cyclic(moves: usize) -> Result<Self, RulesError> {
+        let wins: Vec<(usize, usize)> = (0..moves)
+            .flat_map(|winner| {
+                (1..=moves.saturating_sub(1) / 2)
+                    .map(move |offset| (winner, (winner + moves - offset) % moves))
+            })
+            .collect();
+        Game::new(moves, &wins)
+    }
+
+    /// Rock (0), Paper (1) and Scissors (2).
+    pub fn rock_paper_scissors() -> Self {
+        Game::cyclic(3).unwrap()
+    }
+
+    /// Rock, Paper and Scissors numbered as in the three-move game, then Lizard (3) and
+    /// Spock (4).
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let (rock, paper, scissors, lizard, spock) = (0, 1, 2, 3, 4);
+        Game::new(
+            5,
+            &[
+                (scissors, paper),
+                (paper, rock),
+                (rock, lizard),
+                (lizard, spock),
+                (spock, scissors),
+                (scissors, lizard),
+                (lizard, paper),
+                (paper, spock),
+                (spock, rock),
+                (rock, scissors),
+            ],
+        )
+        .unwrap()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Action> {
+        (0..self.beats.len()).map(Action)
+    }
+
+    /// The move numbered `index`, if the game has one.
+    pub fn action(&self, index: usize) -> Option<Action> {
+        (index < self.beats.len()).then_some(Action(index))
+    }
+
+    pub fn play(&self, my_action: Action, their_action: Action) -> Outcome {
+        if my_action == their_action {
+            Outcome::Draw
+        } else if self.beats[my_action.0][their_action.0] {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn score(&self, my_action: Action, their_action: Action) -> u32 {
+        my_action.0 as u32 + 1 + self.play(my_action, their_action).score()
+    }
+
+    /// The lowest-numbered move that gets `target_outcome` against `their_action`, if any.
+    pub fn necessary_action(
+        &self,
+        their_action: Action,
+        target_outcome: Outcome,
+    ) -> Option<Action> {
+        self.moves()
+            .find(|&my_action| self.play(my_action, their_action) == target_outcome)
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    /// Their move and the index of the second column's letter.
+    type Input = Vec<(Action, usize)>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(input)
+            .map(|(line_number, line)| match line.as_bytes() {
+                [c1 @ b'A'..=b'C', b' ', c2 @ b'X'..=b'Z'] => {
+                    Ok((Action((c1 - b'A') as usize), (c2 - b'X') as usize))
+                }
+                [b'A'..=b'C', b' ', b'X'..=b'Z', ..] => {
+                    Err(ParseError::new(line_number, 4, "end of line"))
+                }
+                [b'A'..=b'C', b' ', ..] => Err(ParseError::new(line_number, 3, "X, Y or Z")),
+                [b'A'..=b'C', ..] => Err(ParseError::new(line_number, 2, "a space")),
+                _ => Err(ParseError::new(line_number, 1, "A, B or C")),
+            })
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> u32 {
+        let game = Game::rock_paper_scissors();
+        rounds
+            .iter()
+            .map(|&(their_action, column)| game.score(Action(column), their_action))
+            .sum()
+    }
+
+    fn part2(rounds: &Self::Input) -> u32 {
+        let game = Game::rock_paper_scissors();
+        rounds
+            .iter()
+            .map(|&(their_action, column)| {
+                let target_outcome = [Outcome::Lose, Outcome::Draw, Outcome::Win][column];
+                // Every move can be beaten, drawn with and lost to in a cyclic game.
+                let my_action = game.necessary_action(their_action, target_outcome).unwrap();
+                game.score(my_action, their_action)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> Vec<(Action, usize)> {
+        Day02::parse(&crate::read_input(Day02::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day02::part1(&input()), 11386);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day02::part2(&input()), 13600);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day02::parse(&crate::read_example(Day02::DAY, 1)).unwrap();
+        assert_eq!(Day02::part1(&input), 15);
+        assert_eq!(Day02::part2(&input), 12);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day02::DAY)) {
+            let input = Day02::parse(&input).unwrap();
+            assert_eq!(Day02::part1(&input), 11386);
+            assert_eq!(Day02::part2(&input), 13600);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day02::parse("A Y\nB Q").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let (rock, paper, scissors, lizard, spock) =
+            (Action(0), Action(1), Action(2), Action(3), Action(4));
+        assert_eq!(game.play(lizard, spock), Outcome::Win);
+        assert_eq!(game.play(spock, rock), Outcome::Win);
+        assert_eq!(game.play(rock, paper), Outcome::Lose);
+        assert_eq!(game.score(spock, scissors), 5 + 6);
+        assert_eq!(game.necessary_action(rock, Outcome::Win), Some(paper));
+        assert_eq!(game.necessary_action(rock, Outcome::Lose), Some(scissors));
+        for action in game.moves() {
+            let wins = game
+                .moves()
+                .filter(|&other| game.play(action, other) == Outcome::Win);
+            assert_eq!(wins.count(), 2);
+        }
+        assert_ne!(game, Game::cyclic(5).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert_eq!(Game::new(2, &[(0, 2)]), Err(RulesError::UnknownMove(2)));
+        assert_eq!(Game::new(2, &[(1, 1)]), Err(RulesError::BeatsItself(1)));
+        assert_eq!(
+            Game::new(2, &[(0, 1), (1, 0)]),
+            Err(RulesError::Contradiction(1, 0))
+        );
+        assert_eq!(
+            Game::new(3, &[(0, 1), (1, 2)]),
+            Err(RulesError::Undecided(0, 2))
+        );
+        assert_eq!(Game::cyclic(4), Err(RulesError::Undecided(0, 2)));
+        assert!(Game::new(0, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_classic_game() {
+        let game = Game::rock_paper_scissors();
+        let (rock, paper, scissors) = (Action(0), Action(1), Action(2));
+        assert_eq!(game.play(paper, rock), Outcome::Win);
+        assert_eq!(game.play(scissors, paper), Outcome::Win);
+        assert_eq!(game.play(rock, scissors), Outcome::Win);
+        assert_eq!(game.score(rock, paper), 1);
+        assert_eq!(game.action(2), Some(scissors));
+        assert_eq!(game.action(3), None);
+        assert_eq!(
+            game.moves().map(Action::index).collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+    }
+
+    /// A cyclic game of 3 to 9 moves, with two moves and an outcome drawn from it.
+    fn round() -> impl Strategy<Value = (Game, Action, Action, Outcome)> {
+        (1..5usize).prop_flat_map(|half| {
+            let moves = 2 * half + 1;
+            (
+                Just(Game::cyclic(moves).unwrap()),
+                (0..moves).prop_map(Action),
+                (0..moves).prop_map(Action),
+                prop_oneof![Just(Outcome::Win), Just(Outcome::Lose), Just(Outcome::Draw)],
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_necessary_action_achieves_outcome((game, _, their_action, target) in round()) {
+            let my_action = game.necessary_action(their_action, target).unwrap();
+            prop_assert_eq!(game.play(my_action, their_action), target);
+        }
+
+        #[test]
+        fn prop_play_is_antisymmetric((game, my_action, their_action, _) in round()) {
+            let flipped = match game.play(their_action, my_action) {
+                Outcome::Win => Outcome::Lose,
+                Outcome::Lose => Outcome::Win,
+                Outcome::Draw => Outcome::Draw,
+            };
+            prop_assert_eq!(game.play(my_action, their_action), flipped);
+        }
+
+        #[test]
+        fn prop_play_round_trips(my_action in 0..3usize, their_action in 0..3usize) {
+            // With three moves the needed move is unique, so it must be the one played.
+            let game = Game::rock_paper_scissors();
+            let (my_action, their_action) = (Action(my_action), Action(their_action));
+            let outcome = game.play(my_action, their_action);
+            prop_assert_eq!(game.necessary_action(their_action, outcome), Some(my_action));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use crate::{parse, ParseError, Solution};
+
+pub struct Day03;
+
+#[derive(Default, Debug)]
+struct Rucksack1 {
+    compartment1: HashSet<char>,
+    compartment2: HashSet<char>,
+}
+
+impl From<&str> for Rucksack1 {
+    fn from(s: &str) -> Self {
+        let mut rucksack: Rucksack1 = Default::default();
+        for (idx, c) in s.chars().enumerate() {
+            if idx < s.len() / 2 {
+                rucksack.compartment1.insert(c);
+            } else {
+                rucksack.compartment2.insert(c);
+            }
+        }
+        rucksack
+    }
+}
+
+fn intersect_all<T>(sets: &[HashSet<T>]) -> HashSet<T>
+where
+    T: std::cmp::Eq + std::hash::Hash + Copy,
+{
+    let mut sets = sets.iter();
+    match sets.next() {
+        Some(set) => sets.fold(set.to_owned(), |result, set| {
+            result.intersection(set).copied().collect()
+        }),
+        None => HashSet::new(),
+    }
+}
+
+fn common_elem<T>(sets: &[HashSet<T>]) -> T
+where
+    T: std::cmp::Eq + std::hash::Hash + Copy,
+{
+    let intersection = intersect_all(sets);
+    intersection.iter().next().unwrap().to_owned()
+}
+
+fn priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => (c as u8 - b'a' + 1) as u32,
+        'A'..='Z' => (c as u8 - b'A' + 27) as u32,
+        _ => panic!("Got unexpected char: {}", c),
+    }
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rucksacks = Vec::new();
+        for (line_number, line) in parse::lines(input) {
+            if let Some(column) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::new(line_number, column + 1, "an item letter"));
+            }
+            if line.len() % 2 == 1 {
+                return Err(ParseError::new(
+                    line_number,
+                    line.len() + 1,
+                    "an item letter",
+                ));
+            }
+            let (first, second) = line.split_at(line.len() / 2);
+            if !first.contains(|c| second.contains(c)) {
+                return Err(ParseError::new(
+                    line_number,
+                    1,
+                    "an item in both compartments",
+                ));
+            }
+            rucksacks.push(line.to_string());
+            // Each group of three shares a badge.
+            if let [.., a, b, c] = &rucksacks[..] {
+                if rucksacks.len() % 3 == 0 && !a.contains(|x| b.contains(x) && c.contains(x)) {
+                    return Err(ParseError::new(
+                        line_number,
+                        1,
+                        "an item shared with the two rucksacks before it",
+                    ));
+                }
+            }
+        }
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::new(
+                rucksacks.len() + 1,
+                1,
+                "another rucksack to complete the group of three",
+            ));
+        }
+        Ok(rucksacks)
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .iter()
+            .map(|items| {
+                let rucksack = Rucksack1::from(&items[..]);
+                let sets = [rucksack.compartment1, rucksack.compartment2];
+                priority(common_elem(&sets))
+            })
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .chunks(3)
+            .map(|group| {
+                let item_sets: Vec<HashSet<char>> = group
+                    .iter()
+                    .map(|items| HashSet::from_iter(items.chars()))
+                    .collect();
+                priority(common_elem(&item_sets))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> Vec<String> {
+        Day03::parse(&crate::read_input(Day03::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day03::part1(&input()), 8109);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day03::part2(&input()), 2738);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day03::parse(&crate::read_example(Day03::DAY, 1)).unwrap();
+        assert_eq!(Day03::part1(&input), 157);
+        assert_eq!(Day03::part2(&input), 70);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day03::DAY)) {
+            let input = Day03::parse(&input).unwrap();
+            assert_eq!(Day03::part1(&input), 8109);
+            assert_eq!(Day03::part2(&input), 2738);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for (input, position, expected) in [
+            ("vJrv\nab1c", (2, 3), "an item letter"),
+            ("abab\nabc", (2, 4), "an item letter"),
+            ("abab\nabcd", (2, 1), "an item in both compartments"),
+            (
+                "aa\nbb\ncc",
+                (3, 1),
+                "an item shared with the two rucksacks before it",
+            ),
+            (
+                "abab\ncdcd",
+                (3, 1),
+                "another rucksack to complete the group of three",
+            ),
+        ] {
+            let err = Day03::parse(input).unwrap_err();
+            assert_eq!((err.line, err.column), position, "{:?}", input);
+            assert_eq!(err.expected, expected);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{parse, ParseError, Solution};
+
+pub struct Day04;
+
+#[derive(Debug)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+
+    fn contains(&self, other: &Range) -> bool {
+        self.start <= other.start && self.end >= other.end
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        (self.start <= other.start && other.start <= self.end)
+            || (other.start <= self.start && self.start <= other.end)
+            || self.contains(other)
+            || other.contains(self)
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Range, Range)>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        peg::parser! {
+            grammar ranges_parser() for str {
+                pub(crate) rule list() -> Vec<(Range, Range)>
+                    = p:pair() ++ nl() nl()* { p }
+
+                rule pair() -> (Range, Range)
+                    = r1:range() "," r2:range() { (r1, r2) }
+
+                rule range() -> Range
+                    = start:number() "-" end:number() { Range::new(start, end) }
+
+                rule number() -> u32
+                    = quiet!{ #{parse::unsigned} } / expected!("a number")
+
+                rule nl()
+                    = "\r"? "\n"
+            }
+        }
+
+        Ok(ranges_parser::list(input)?)
+    }
+
+    fn part1(ranges: &Self::Input) -> u32 {
+        ranges
+            .iter()
+            .filter(|(r1, r2)| r1.contains(r2) || r2.contains(r1))
+            .count() as u32
+    }
+
+    fn part2(ranges: &Self::Input) -> u32 {
+        ranges.iter().filter(|(r1, r2)| r1.overlaps(r2)).count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> Vec<(Range, Range)> {
+        Day04::parse(&crate::read_input(Day04::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day04::part1(&input()), 657);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day04::part2(&input()), 938);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day04::parse(&crate::read_example(Day04::DAY, 1)).unwrap();
+        assert_eq!(Day04::part1(&input), 2);
+        assert_eq!(Day04::part2(&input), 4);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day04::DAY)) {
+            let input = Day04::parse(&input).unwrap();
+            assert_eq!(Day04::part1(&input), 657);
+            assert_eq!(Day04::part2(&input), 938);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day04::parse("2-4,6-8\n2-3,4x5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+
+    fn range() -> impl Strategy<Value = Range> {
+        (0u32..100, 0u32..100).prop_map(|(a, b)| Range::new(a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_overlaps_is_symmetric(r1 in range(), r2 in range()) {
+            prop_assert_eq!(r1.overlaps(&r2), r2.overlaps(&r1));
+        }
+
+        #[test]
+        fn prop_contains_implies_overlaps(r1 in range(), r2 in range()) {
+            prop_assert!(r1.contains(&r1));
+            if r1.contains(&r2) {
+                prop_assert!(r1.overlaps(&r2));
+            }
+        }
+
+        #[test]
+        fn prop_overlaps_iff_shared_section(r1 in range(), r2 in range()) {
+            let shared = (r1.start..=r1.end).any(|section| (r2.start..=r2.end).contains(&section));
+            prop_assert_eq!(r1.overlaps(&r2), shared);
+        }
+    }
+}
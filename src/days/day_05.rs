@@ -0,0 +1,323 @@
+use crate::{parse, ParseError, Solution};
+
+pub struct Day05;
+
+#[derive(Debug, Clone)]
+pub struct Crates {
+    stacks: Vec<Vec<char>>,
+}
+
+impl Crates {
+    fn new(num_stacks: usize) -> Self {
+        Crates {
+            stacks: vec![Default::default(); num_stacks],
+        }
+    }
+
+    /// Layers are listed top first and may be shorter than `num_stacks`, but not longer.
+    fn from_layers(layers: &[Vec<Option<char>>], num_stacks: usize) -> Self {
+        let mut crates = Crates::new(num_stacks);
+        for layer in layers.iter().rev() {
+            for (idx, crate_slot) in layer.iter().enumerate() {
+                if let Some(c) = crate_slot {
+                    crates.stacks[idx].push(*c);
+                }
+            }
+        }
+        crates
+    }
+
+    fn execute(&mut self, i: &[Instruction], crane: Crane) {
+        for instruction in i.iter() {
+            match crane {
+                Crane::CrateMover9000 => self.execute_instruction_9000(instruction),
+                Crane::CrateMover9001 => self.execute_instruction_9001(instruction),
+            }
+        }
+    }
+
+    fn execute_instruction_9000(&mut self, i: &Instruction) {
+        for _ in 0..i.quantity {
+            let c = self.stacks[(i.src - 1) as usize].pop().unwrap();
+            self.stacks[(i.dst - 1) as usize].push(c);
+        }
+    }
+
+    fn execute_instruction_9001(&mut self, i: &Instruction) {
+        let mut crates_to_move: Vec<char> = Vec::new();
+        for _ in 0..i.quantity {
+            crates_to_move.push(self.stacks[(i.src - 1) as usize].pop().unwrap());
+        }
+        while let Some(c) = crates_to_move.pop() {
+            self.stacks[(i.dst - 1) as usize].push(c);
+        }
+    }
+
+    fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().unwrap())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    quantity: u32,
+    src: u32,
+    dst: u32,
+}
+
+impl Instruction {
+    fn new(quantity: u32, src: u32, dst: u32) -> Self {
+        Instruction { quantity, src, dst }
+    }
+}
+
+/// Rejects a move that takes more crates than its stack holds, or moves that leave a stack
+/// empty, reporting them on the move's line.
+fn check_moves(
+    crates: &Crates,
+    moves: &[(usize, Instruction)],
+    index_line: usize,
+) -> Result<(), ParseError> {
+    let mut heights: Vec<u32> = crates
+        .stacks
+        .iter()
+        .map(|stack| stack.len() as u32)
+        .collect();
+    // The line of the last move to take crates from each stack.
+    let mut last_taken: Vec<Option<usize>> = vec![None; heights.len()];
+    for (line, i) in moves {
+        let (src, dst) = ((i.src - 1) as usize, (i.dst - 1) as usize);
+        if i.quantity > heights[src] {
+            let expected = format!(
+                "a quantity of at most {}, the height of stack {}",
+                heights[src], i.src
+            );
+            return Err(ParseError::new(*line, 6, expected));
+        }
+        heights[src] -= i.quantity;
+        heights[dst] += i.quantity;
+        last_taken[src] = Some(*line);
+    }
+    match heights.iter().position(|&height| height == 0) {
+        Some(idx) => Err(match last_taken[idx] {
+            Some(line) => ParseError::new(
+                line,
+                6,
+                format!("a move that leaves a crate on stack {}", idx + 1),
+            ),
+            None => ParseError::new(
+                index_line,
+                4 * idx + 2,
+                format!("a crate on stack {} by the last move", idx + 1),
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Crates, Vec<Instruction>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        peg::parser! {
+            grammar parser() for str {
+                pub(crate) rule parse() -> (Vec<Vec<Option<char>>>, usize, Vec<(usize, Instruction)>)
+                    = l:crates_layer() ++ nl() nl() n:crates_index() nl() nl() i:instructions(n) nl()* ![_] { (l, n, i) }
+
+                rule crates_layer() -> Vec<Option<char>>
+                    = c:crate_slot() ++ " " { c }
+
+                rule crate_slot() -> Option<char>
+                    = "[" c:$(['A'..='Z']) "]" { Some(c.chars().next().unwrap()) }
+                    / "   " { None }
+
+                rule crates_index() -> usize
+                    = i:(" " ['0'..='9'] " ") ++ " " { i.len() }
+
+                rule instructions(stacks: usize) -> Vec<(usize, Instruction)>
+                    = (p:position!() i:instruction(stacks) { (p, i) }) ++ nl()
+
+                rule instruction(stacks: usize) -> Instruction
+                    = "move " n1:number() " from " n2:stack(stacks) " to " n3:stack(stacks) { Instruction::new(n1, n2, n3) }
+
+                rule stack(stacks: usize) -> u32
+                    = quiet!{ n:number() {? if (1..=stacks as u32).contains(&n) { Ok(n) } else { Err("") } } }
+                    / expected!("a stack number")
+
+                rule number() -> u32
+                    = quiet!{ #{parse::unsigned} } / expected!("a number")
+
+                rule nl()
+                    = "\r"? "\n"
+            }
+        }
+
+        let (layers, num_stacks, instructions) = parser::parse(input)?;
+        if let Some(idx) = layers.iter().position(|layer| layer.len() > num_stacks) {
+            return Err(ParseError::new(
+                idx + 1,
+                4 * num_stacks + 1,
+                format!("the end of the layer after {} stacks", num_stacks),
+            ));
+        }
+        let crates = Crates::from_layers(&layers, num_stacks);
+        let moves: Vec<(usize, Instruction)> = instructions
+            .into_iter()
+            .map(|(pos, i)| (input[..pos].matches('\n').count() + 1, i))
+            .collect();
+        check_moves(&crates, &moves, layers.len() + 1)?;
+        Ok((crates, moves.into_iter().map(|(_, i)| i).collect()))
+    }
+
+    fn part1((crates, instructions): &Self::Input) -> String {
+        let mut crates = crates.clone();
+        crates.execute(instructions, Crane::CrateMover9000);
+        crates.top_crates()
+    }
+
+    fn part2((crates, instructions): &Self::Input) -> String {
+        let mut crates = crates.clone();
+        crates.execute(instructions, Crane::CrateMover9001);
+        crates.top_crates()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> (Crates, Vec<Instruction>) {
+        Day05::parse(&crate::read_input(Day05::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day05::part1(&input()), "HBTMTBSDC");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day05::part2(&input()), "PQTJRSHWS");
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day05::parse(&crate::read_example(Day05::DAY, 1)).unwrap();
+        assert_eq!(Day05::part1(&input), "CMZ");
+        assert_eq!(Day05::part2(&input), "MCD");
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day05::DAY)) {
+            let input = Day05::parse(&input).unwrap();
+            assert_eq!(Day05::part1(&input), "HBTMTBSDC");
+            assert_eq!(Day05::part2(&input), "PQTJRSHWS");
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day05::parse("[A]\n 1 \n\nmove 1 from x to 1")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (4, 13));
+        let err = Day05::parse("[A]\n 1 \n\nmove 1 from 1 to 2")
+            .err()
+            .unwrap();
+        assert_eq!((err.line, err.column), (4, 18));
+    }
+
+    #[test]
+    fn test_impossible_moves() {
+        for (input, position, expected) in [
+            (
+                "[A]\n 1 \n\nmove 2 from 1 to 1",
+                (4, 6),
+                "a quantity of at most 1, the height of stack 1",
+            ),
+            (
+                "[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 1 from 2 to 1\nmove 1 from 1 to 1",
+                (5, 6),
+                "a move that leaves a crate on stack 2",
+            ),
+            (
+                "[A]    \n 1   2 \n\nmove 1 from 1 to 1",
+                (2, 6),
+                "a crate on stack 2 by the last move",
+            ),
+        ] {
+            let err = Day05::parse(input).unwrap_err();
+            assert_eq!((err.line, err.column), position, "{:?}", input);
+            assert_eq!(err.expected, expected);
+        }
+    }
+
+    #[test]
+    fn test_uneven_layers() {
+        let input = Day05::parse("[B]\n[A] [C]\n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+        assert_eq!(Day05::part1(&input), "AB");
+        let err = Day05::parse("[B] [D]\n[A] [C]\n 1 \n\nmove 1 from 1 to 1").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+
+    /// Stacks of crates and a sequence of moves that never takes from an empty stack.
+    fn crates_and_moves() -> impl Strategy<Value = (Crates, Vec<Instruction>)> {
+        let stacks = prop::collection::vec(
+            prop::collection::vec(prop::char::range('A', 'Z'), 0..8),
+            1..6,
+        );
+        let moves = prop::collection::vec((any::<u32>(), any::<usize>(), any::<usize>()), 0..30);
+        (stacks, moves).prop_map(|(stacks, moves)| {
+            let mut heights: Vec<u32> = stacks.iter().map(|stack| stack.len() as u32).collect();
+            let mut instructions = Vec::new();
+            for (quantity, src, dst) in moves {
+                let nonempty: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+                if nonempty.is_empty() {
+                    break;
+                }
+                let src = nonempty[src % nonempty.len()];
+                let dst = dst % heights.len();
+                let quantity = 1 + quantity % heights[src];
+                heights[src] -= quantity;
+                heights[dst] += quantity;
+                instructions.push(Instruction::new(quantity, src as u32 + 1, dst as u32 + 1));
+            }
+            (Crates { stacks }, instructions)
+        })
+    }
+
+    fn sorted_crates(crates: &Crates) -> Vec<char> {
+        let mut all: Vec<char> = crates.stacks.iter().flatten().copied().collect();
+        all.sort();
+        all
+    }
+
+    proptest! {
+        #[test]
+        fn prop_execute_conserves_crates((crates, instructions) in crates_and_moves()) {
+            let mut crates_9000 = crates.clone();
+            crates_9000.execute(&instructions, Crane::CrateMover9000);
+            let mut crates_9001 = crates.clone();
+            crates_9001.execute(&instructions, Crane::CrateMover9001);
+
+            prop_assert_eq!(sorted_crates(&crates_9000), sorted_crates(&crates));
+            prop_assert_eq!(sorted_crates(&crates_9001), sorted_crates(&crates));
+            let heights = |crates: &Crates| crates.stacks.iter().map(Vec::len).collect::<Vec<_>>();
+            prop_assert_eq!(heights(&crates_9000), heights(&crates_9001));
+        }
+    }
+}
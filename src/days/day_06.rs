@@ -0,0 +1,118 @@
+use crate::{parse, ParseError, Solution};
+
+pub struct Day06;
+
+fn unique(chars: &[u8]) -> bool {
+    for i in 0..chars.len() {
+        for j in (i + 1)..chars.len() {
+            if chars[i] == chars[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// The number of characters read by the end of the first `len` distinct characters.
+fn marker(datastream: &str, len: usize) -> Option<u32> {
+    datastream
+        .as_bytes()
+        .windows(len)
+        .position(unique)
+        .map(|start| (start + len) as u32)
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = parse::lines(input);
+        let datastream = lines.next().map_or("", |(_, line)| line);
+        let end = datastream.len() + 1;
+        if let Some(column) = datastream.find(|c: char| !c.is_ascii_lowercase()) {
+            return Err(ParseError::new(1, column + 1, "a lowercase letter"));
+        }
+        if lines.next().is_some() {
+            return Err(ParseError::new(1, end, "a lowercase letter"));
+        }
+        if datastream.len() < 14 {
+            return Err(ParseError::new(1, end, "at least 14 characters"));
+        }
+        // A start-of-message marker also contains a start-of-packet one.
+        if marker(datastream, 14).is_none() {
+            return Err(ParseError::new(1, end, "a start-of-message marker"));
+        }
+        Ok(datastream.to_string())
+    }
+
+    fn part1(datastream: &Self::Input) -> u32 {
+        marker(datastream, 4).expect("parse checks for a marker")
+    }
+
+    fn part2(datastream: &Self::Input) -> u32 {
+        marker(datastream, 14).expect("parse checks for a marker")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> String {
+        Day06::parse(&crate::read_input(Day06::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day06::part1(&input()), 1804);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day06::part2(&input()), 2508);
+    }
+
+    #[test]
+    fn test_examples() {
+        let answers = [(7, 19), (5, 23), (6, 23)];
+        for (idx, (answer1, answer2)) in answers.into_iter().enumerate() {
+            let input = Day06::parse(&crate::read_example(Day06::DAY, idx + 1)).unwrap();
+            assert_eq!(Day06::part1(&input), answer1);
+            assert_eq!(Day06::part2(&input), answer2);
+        }
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day06::DAY)) {
+            let input = Day06::parse(&input).unwrap();
+            assert_eq!(Day06::part1(&input), 1804);
+            assert_eq!(Day06::part2(&input), 2508);
+        }
+    }
+
+    #[test]
+    fn test_marker_at_end() {
+        let input = Day06::parse("aaaaaaaaaaaaaaaaaabcdefghijklmn").unwrap();
+        assert_eq!(Day06::part1(&input), 21);
+        assert_eq!(Day06::part2(&input), 31);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for (input, column, expected) in [
+            ("abc", 4, "at least 14 characters"),
+            ("abcdefgh1jklmnop", 9, "a lowercase letter"),
+            ("abcdefghijklmn\nab", 15, "a lowercase letter"),
+            ("aaaaaaaaaaaaaaaa", 17, "a start-of-message marker"),
+        ] {
+            let err = Day06::parse(input).unwrap_err();
+            assert_eq!((err.line, err.column), (1, column), "{:?}", input);
+            assert_eq!(err.expected, expected);
+        }
+    }
+}
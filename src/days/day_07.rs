@@ -0,0 +1,402 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{ParseError, Solution};
+
+pub struct Day07;
+
+pub mod input {
+    pub struct Invocation {
+        pub command: Command,
+        pub output: Vec<FsEntry>,
+    }
+
+    impl Invocation {
+        fn new(command: Command, output: Vec<FsEntry>) -> Self {
+            Self { command, output }
+        }
+    }
+
+    pub enum Command {
+        Ls,
+        CdRoot,
+        CdParent,
+        CdDir(String),
+    }
+
+    impl Command {
+        fn new_cd(arg: &str) -> Self {
+            match arg {
+                "/" => Self::CdRoot,
+                ".." => Self::CdParent,
+                _ => Self::CdDir(arg.to_string()),
+            }
+        }
+    }
+
+    pub enum FsEntry {
+        Dir(String),
+        File(String, usize),
+    }
+
+    /// The invocations in `input`, each with the line it starts on.
+    pub fn parse_input(input: &str) -> Result<Vec<(usize, Invocation)>, crate::ParseError> {
+        peg::parser! {
+            grammar parser() for str {
+                pub(crate) rule input() -> Vec<(usize, Invocation)>
+                    = i:(p:position!() i:invocation() { (p, i) }) ++ nl() nl()* ![_] { i }
+
+                rule invocation() -> Invocation
+                    = c:cd() { c }
+                    / l:ls() { l }
+
+                rule cd() -> Invocation
+                    = "$ cd " arg:$([^ '\r' | '\n']+) {
+                        Invocation::new(Command::new_cd(arg), Default::default())
+                    }
+
+                rule ls() -> Invocation
+                    = "$ ls" nl() e:entries() {
+                        Invocation::new(Command::Ls, e)
+                    }
+
+                rule entries() -> Vec<FsEntry>
+                    = e:entry() ++ nl() { e }
+
+                rule entry() -> FsEntry
+                    = size:size() " " name:$([^ '\r' | '\n']+) { FsEntry::File(name.to_string(), size) }
+                    / "dir " name:$([^ '\r' | '\n']+) { FsEntry::Dir(name.to_string()) }
+
+                rule size() -> usize
+                    = quiet!{ #{crate::parse::unsigned} } / expected!("a file size")
+
+                rule nl()
+                    = "\r"? "\n"
+            }
+        }
+
+        let invocations = parser::input(input)?;
+        Ok(invocations
+            .into_iter()
+            .map(|(pos, invocation)| (input[..pos].matches('\n').count() + 1, invocation))
+            .collect())
+    }
+}
+
+/// Rejects a `cd ..` at the root or a `cd` into a directory that hasn't been listed, which
+/// would leave nowhere to go.
+fn check_cds(invocations: &[(usize, input::Invocation)]) -> Result<(), ParseError> {
+    let mut cwd: Vec<&str> = Vec::new();
+    let mut listed: HashSet<Vec<&str>> = HashSet::new();
+    for (line, invocation) in invocations {
+        match &invocation.command {
+            input::Command::CdRoot => cwd.clear(),
+            input::Command::CdParent => {
+                if cwd.pop().is_none() {
+                    return Err(ParseError::new(*line, 6, "a directory below the root"));
+                }
+            }
+            input::Command::CdDir(name) => {
+                cwd.push(name);
+                if !listed.contains(&cwd) {
+                    return Err(ParseError::new(
+                        *line,
+                        6,
+                        "a directory listed in the current one",
+                    ));
+                }
+            }
+            input::Command::Ls => {
+                for entry in invocation.output.iter() {
+                    if let input::FsEntry::Dir(name) = entry {
+                        listed.insert([&cwd[..], &[name.as_str()]].concat());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+struct Dir {
+    parent: Option<Rc<RefCell<Dir>>>,
+    dirs: HashMap<String, Rc<RefCell<Dir>>>,
+    files: HashMap<String, usize>,
+    size: Option<usize>,
+}
+
+impl Dir {
+    fn new(parent: Option<Rc<RefCell<Dir>>>) -> Self {
+        Self {
+            parent,
+            dirs: Default::default(),
+            files: Default::default(),
+            size: None,
+        }
+    }
+
+    fn size(&mut self) -> usize {
+        if let Some(size) = self.size {
+            return size;
+        }
+        let size = self.calculate_size();
+        self.size = Some(size);
+        size
+    }
+
+    fn calculate_size(&self) -> usize {
+        let mut result = 0usize;
+        for dir in self.dirs.values() {
+            result += dir.borrow_mut().size()
+        }
+        for file_size in self.files.values() {
+            result += file_size
+        }
+        result
+    }
+}
+
+struct Filesystem {
+    root: Rc<RefCell<Dir>>,
+    current_dir: Rc<RefCell<Dir>>,
+    dirs: Vec<Rc<RefCell<Dir>>>,
+}
+
+impl Filesystem {
+    fn new() -> Self {
+        let root = Rc::new(RefCell::new(Dir::new(None)));
+        Self {
+            root: root.clone(),
+            current_dir: root,
+            dirs: Default::default(),
+        }
+    }
+
+    fn from_invocations(invocations: &[input::Invocation]) -> Self {
+        let mut filesystem = Filesystem::new();
+        for invocation in invocations.iter() {
+            filesystem.incorporate(invocation);
+        }
+        filesystem
+    }
+
+    /// The size of every directory, the root included.
+    fn dir_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(&self.root)
+            .chain(self.dirs.iter())
+            .map(|dir| dir.borrow_mut().size())
+    }
+
+    fn incorporate(&mut self, invocation: &input::Invocation) {
+        match &invocation.command {
+            input::Command::CdRoot => self.current_dir = self.root.clone(),
+            input::Command::CdParent => {
+                let new_dir = self.current_dir.borrow().parent.as_ref().unwrap().clone();
+                self.current_dir = new_dir;
+            }
+            input::Command::CdDir(name) => {
+                let new_dir = self.current_dir.borrow().dirs.get(name).unwrap().clone();
+                self.current_dir = new_dir;
+            }
+            input::Command::Ls => {
+                for entry in invocation.output.iter() {
+                    self.add_entry(&mut self.current_dir.clone(), entry);
+                }
+            }
+        }
+    }
+
+    fn add_entry(&mut self, target: &mut Rc<RefCell<Dir>>, entry: &input::FsEntry) {
+        match entry {
+            input::FsEntry::Dir(name) => {
+                let new_dir = Rc::new(RefCell::new(Dir::new(Some(target.clone()))));
+                target
+                    .borrow_mut()
+                    .dirs
+                    .entry(name.clone())
+                    .or_insert_with(|| new_dir.clone());
+                self.dirs.push(new_dir);
+            }
+            input::FsEntry::File(name, size) => {
+                target
+                    .borrow_mut()
+                    .files
+                    .entry(name.clone())
+                    .or_insert(*size);
+            }
+        }
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<input::Invocation>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let invocations = input::parse_input(input)?;
+        check_cds(&invocations)?;
+        Ok(invocations
+            .into_iter()
+            .map(|(_, invocation)| invocation)
+            .collect())
+    }
+
+    fn part1(invocations: &Self::Input) -> usize {
+        let filesystem = Filesystem::from_invocations(invocations);
+
+        filesystem.dir_sizes().filter(|size| *size <= 100000).sum()
+    }
+
+    fn part2(invocations: &Self::Input) -> usize {
+        let filesystem = Filesystem::from_invocations(invocations);
+
+        // Need 30000000 free and filesystem has total capacity of 70000000.  Therefore any
+        // usage beyond (70000000 - 30000000) = 40000000 has to be deleted.
+        let root_size = filesystem.root.borrow_mut().size();
+        let must_delete_at_least = root_size.saturating_sub(40000000);
+
+        // The root always qualifies, so there is a smallest candidate.
+        filesystem
+            .dir_sizes()
+            .filter(|size| *size >= must_delete_at_least)
+            .fold(root_size, usize::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> Vec<input::Invocation> {
+        Day07::parse(&crate::read_input(Day07::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day07::part1(&input()), 1501149);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day07::part2(&input()), 10096985);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day07::parse(&crate::read_example(Day07::DAY, 1)).unwrap();
+        assert_eq!(Day07::part1(&input), 95437);
+        assert_eq!(Day07::part2(&input), 24933642);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day07::DAY)) {
+            let input = Day07::parse(&input).unwrap();
+            assert_eq!(Day07::part1(&input), 1501149);
+            assert_eq!(Day07::part2(&input), 10096985);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day07::parse("$ cd /\n$ ls\nabc b.txt").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        for (input, line, expected) in [
+            ("$ cd /\n$ cd ..", 2, "a directory below the root"),
+            ("$ cd x", 1, "a directory listed in the current one"),
+            (
+                "$ cd /\n$ ls\ndir a\n$ cd a\n$ cd a",
+                5,
+                "a directory listed in the current one",
+            ),
+        ] {
+            let err = Day07::parse(input).err().unwrap();
+            assert_eq!((err.line, err.column), (line, 6), "{:?}", input);
+            assert_eq!(err.expected, expected);
+        }
+    }
+
+    #[test]
+    fn test_small_disk() {
+        let input = Day07::parse("$ cd /\n$ ls\n100 a\ndir b\n$ cd b\n$ ls\n10 c").unwrap();
+        assert_eq!(Day07::part1(&input), 110 + 10);
+        assert_eq!(Day07::part2(&input), 10);
+        let input = Day07::parse("$ cd /\n$ ls\n100 a").unwrap();
+        assert_eq!(Day07::part2(&input), 100);
+    }
+
+    #[derive(Debug, Clone)]
+    struct Tree {
+        files: Vec<usize>,
+        dirs: Vec<Tree>,
+    }
+
+    impl Tree {
+        fn total_size(&self) -> usize {
+            self.files.iter().sum::<usize>() + self.dirs.iter().map(Tree::total_size).sum::<usize>()
+        }
+
+        /// The terminal session that explores this tree from the current directory.
+        fn invocations(&self, invocations: &mut Vec<input::Invocation>) {
+            let files = self
+                .files
+                .iter()
+                .enumerate()
+                .map(|(i, size)| input::FsEntry::File(format!("f{}", i), *size));
+            let dirs = (0..self.dirs.len()).map(|i| input::FsEntry::Dir(format!("d{}", i)));
+            invocations.push(input::Invocation {
+                command: input::Command::Ls,
+                output: files.chain(dirs).collect(),
+            });
+            for (i, dir) in self.dirs.iter().enumerate() {
+                invocations.push(input::Invocation {
+                    command: input::Command::CdDir(format!("d{}", i)),
+                    output: Vec::new(),
+                });
+                dir.invocations(invocations);
+                invocations.push(input::Invocation {
+                    command: input::Command::CdParent,
+                    output: Vec::new(),
+                });
+            }
+        }
+    }
+
+    fn tree() -> impl Strategy<Value = Tree> {
+        let files = || prop::collection::vec(0usize..1_000_000, 0..4);
+        let leaf = files().prop_map(|files| Tree {
+            files,
+            dirs: Vec::new(),
+        });
+        leaf.prop_recursive(4, 40, 4, move |inner| {
+            (files(), prop::collection::vec(inner, 0..4))
+                .prop_map(|(files, dirs)| Tree { files, dirs })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_root_size_is_sum_of_files(tree in tree()) {
+            let mut invocations = vec![input::Invocation {
+                command: input::Command::CdRoot,
+                output: Vec::new(),
+            }];
+            tree.invocations(&mut invocations);
+            let filesystem = Filesystem::from_invocations(&invocations);
+
+            let root_size = filesystem.root.borrow_mut().size();
+            prop_assert_eq!(root_size, tree.total_size());
+            for dir in filesystem.dirs.iter() {
+                prop_assert!(dir.borrow_mut().size() <= root_size);
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    grid::{Direction, Grid, Point},
+    parse, ParseError, Solution,
+};
+
+pub struct Day08;
+
+#[derive(Debug)]
+pub struct Trees {
+    heights: Grid<u8>,
+}
+
+impl Trees {
+    fn new(heights: Grid<u8>) -> Self {
+        Self { heights }
+    }
+
+    fn is_visible(&self, point: Point) -> bool {
+        if self.heights.is_edge(point) {
+            return true;
+        }
+        let tree_height = self.heights[point];
+        Direction::CARDINAL.into_iter().any(|direction| {
+            self.heights
+                .ray(point, direction)
+                .all(|(_, other_height)| tree_height > *other_height)
+        })
+    }
+
+    fn scenic_score(&self, point: Point) -> usize {
+        if self.heights.is_edge(point) {
+            return 0;
+        }
+        let tree_height = self.heights[point];
+        Direction::CARDINAL
+            .into_iter()
+            .map(|direction| {
+                let mut result: usize = 0;
+                for (_, other_height) in self.heights.ray(point, direction) {
+                    result += 1;
+                    if tree_height <= *other_height {
+                        break;
+                    }
+                }
+                result
+            })
+            .product()
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Trees;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Trees::new(parse::digit_grid(input)?))
+    }
+
+    fn part1(trees: &Self::Input) -> usize {
+        trees
+            .heights
+            .points()
+            .filter(|point| trees.is_visible(*point))
+            .count()
+    }
+
+    fn part2(trees: &Self::Input) -> usize {
+        trees
+            .heights
+            .points()
+            .map(|point| trees.scenic_score(point))
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn input() -> Trees {
+        Day08::parse(&crate::read_input(Day08::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day08::part1(&input()), 1782);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(Day08::part2(&input()), 474606);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day08::parse(&crate::read_example(Day08::DAY, 1)).unwrap();
+        assert_eq!(Day08::part1(&input), 21);
+        assert_eq!(Day08::part2(&input), 8);
+    }
+
+    #[test]
+    fn test_line_endings() {
+        for input in crate::test_util::line_ending_variants(&crate::read_input(Day08::DAY)) {
+            let input = Day08::parse(&input).unwrap();
+            assert_eq!(Day08::part1(&input), 1782);
+            assert_eq!(Day08::part2(&input), 474606);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day08::parse("123\n4x6").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Day08::parse("123\n45").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    fn trees() -> impl Strategy<Value = Trees> {
+        (1usize..12, 1usize..12).prop_flat_map(|(width, height)| {
+            prop::collection::vec(0u8..10, width * height)
+                .prop_map(move |cells| Trees::new(Grid::new(width, height, cells)))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_perimeter_is_visible(trees in trees()) {
+            for point in trees.heights.points().filter(|point| trees.heights.is_edge(*point)) {
+                prop_assert!(trees.is_visible(point), "{:?} is hidden", point);
+            }
+        }
+    }
+}
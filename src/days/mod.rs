@@ -0,0 +1,27 @@
+use crate::Puzzle;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+
+pub fn all() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day_01::Day01>(),
+        Puzzle::of::<day_02::Day02>(),
+        Puzzle::of::<day_03::Day03>(),
+        Puzzle::of::<day_04::Day04>(),
+        Puzzle::of::<day_05::Day05>(),
+        Puzzle::of::<day_06::Day06>(),
+        Puzzle::of::<day_07::Day07>(),
+        Puzzle::of::<day_08::Day08>(),
+    ]
+}
+
+pub fn get(day: u8) -> Option<Puzzle> {
+    all().into_iter().find(|puzzle| puzzle.day == day)
+}
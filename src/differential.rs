@@ -0,0 +1,267 @@
+//! Runs the real solutions and the naive ones in `reference` side by side on generated
+//! inputs, shrinking the first input they disagree on to a small reproducer.
+
+use std::{fmt, ops::Range};
+
+use crate::{generate, reference, runner, Answer, Puzzle};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    pub day: u8,
+    pub part: u8,
+    pub expected: Answer,
+    /// The real solution's answer, or how it failed.
+    pub actual: Result<Answer, String>,
+    pub input: String,
+    /// How many lines the input had before it was minimized.
+    pub original_lines: usize,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let actual = match &self.actual {
+            Ok(answer) => answer.to_string(),
+            Err(failure) => failure.clone(),
+        };
+        writeln!(
+            f,
+            "day {} part {}: expected {} but got {} on this input (minimized from {} lines):",
+            self.day, self.part, self.expected, actual, self.original_lines
+        )?;
+        writeln!(f, "{}", self.input)
+    }
+}
+
+const PANIC: &str = "a panic";
+
+/// Both parts of the real solution. Failures are described as either a panic or a parse error.
+fn solve(puzzle: &Puzzle, input: &str) -> Vec<Result<Answer, String>> {
+    match runner::run(puzzle, input, &[1, 2]) {
+        Ok(rows) => rows
+            .into_iter()
+            .map(|row| {
+                row.result
+                    .map_err(|message| format!("{} ({})", PANIC, message))
+            })
+            .collect(),
+        Err(err) => vec![Err(format!("a parse error ({})", err)); 2],
+    }
+}
+
+/// Whether `actual` disagrees with `expected` in the same way as `original` did: a wrong
+/// answer stays a wrong answer, a panic stays a panic and a parse error stays a parse error.
+fn same_failure(
+    original: &Result<Answer, String>,
+    expected: &Answer,
+    actual: &Result<Answer, String>,
+) -> bool {
+    match (original, actual) {
+        (Ok(_), Ok(answer)) => answer != expected,
+        (Err(original), Err(failure)) => original.starts_with(PANIC) == failure.starts_with(PANIC),
+        _ => false,
+    }
+}
+
+/// Compares `puzzle` against its reference solution on one input. Inputs that the reference
+/// rejects are skipped, but the real solution rejecting one the reference solves is a
+/// divergence.
+pub fn compare(puzzle: &Puzzle, input: &str) -> Option<Divergence> {
+    let expected = reference::solve(puzzle.day, input)?;
+    let actual = solve(puzzle, input);
+    let part = (0..2).find(|&idx| actual[idx].as_ref() != Ok(&expected[idx]))?;
+
+    let original = actual[part].clone();
+    let minimized = minimize(input, |candidate| {
+        let Some(expected) = reference::solve(puzzle.day, candidate) else {
+            return false;
+        };
+        let actual = solve(puzzle, candidate);
+        same_failure(&original, &expected[part], &actual[part])
+    });
+
+    let expected = reference::solve(puzzle.day, &minimized).unwrap();
+    let actual = solve(puzzle, &minimized);
+    Some(Divergence {
+        day: puzzle.day,
+        part: part as u8 + 1,
+        expected: expected[part].clone(),
+        actual: actual[part].clone(),
+        input: minimized,
+        original_lines: input.lines().count(),
+    })
+}
+
+/// Hand-written inputs for boundaries that random generation is unlikely to hit.
+fn edge_cases(day: u8) -> &'static [&'static str] {
+    match day {
+        // The start-of-message marker is the very last window.
+        6 => &["aaaaaaaaaaaaaaaaaabcdefghijklmn"],
+        // A directory of exactly the part 1 limit.
+        7 => &["$ cd /\n$ ls\ndir a\ndir b\n$ cd a\n$ ls\n100000 x.txt\n$ cd ..\n$ cd b\n$ ls\n45000000 big.bin"],
+        _ => &[],
+    }
+}
+
+/// Compares `puzzle` against its reference on the day's edge cases, then on a generated input
+/// of `size` for each seed, returning the first divergence found.
+pub fn check(puzzle: &Puzzle, size: usize, seeds: Range<u64>) -> Result<(), Divergence> {
+    for input in edge_cases(puzzle.day) {
+        if let Some(divergence) = compare(puzzle, input) {
+            return Err(divergence);
+        }
+    }
+    for seed in seeds {
+        let input = generate::generate(puzzle.day, size, seed).unwrap();
+        if let Some(divergence) = compare(puzzle, &input) {
+            return Err(divergence);
+        }
+    }
+    Ok(())
+}
+
+/// Shrinks `input` while it stays `interesting` by deleting ever smaller runs of lines, or of
+/// characters once a single line is left.
+pub fn minimize(input: &str, interesting: impl Fn(&str) -> bool) -> String {
+    let lines: Vec<&str> = input.lines().collect();
+    let lines = shrink(lines, "\n", &interesting);
+    if lines.len() != 1 {
+        return lines.join("\n");
+    }
+    let chars: Vec<String> = lines[0].chars().map(String::from).collect();
+    let chars: Vec<&str> = chars.iter().map(String::as_str).collect();
+    shrink(chars, "", &interesting).concat()
+}
+
+fn shrink<'a>(
+    mut units: Vec<&'a str>,
+    separator: &str,
+    interesting: &impl Fn(&str) -> bool,
+) -> Vec<&'a str> {
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        let mut removed_any = false;
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && interesting(&candidate.join(separator)) {
+                units = candidate;
+                removed_any = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed_any {
+            chunk /= 2;
+        }
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days::{self, day_04::Day04},
+        ParseError, Solution,
+    };
+
+    #[test]
+    fn test_days_agree_with_reference() {
+        for puzzle in days::all() {
+            for size in [1, 10, 50] {
+                if let Err(divergence) = check(&puzzle, size, 0..10) {
+                    panic!("{}", divergence);
+                }
+            }
+            let input = crate::read_input(puzzle.day);
+            assert_eq!(compare(&puzzle, &input), None, "day {}", puzzle.day);
+        }
+    }
+
+    /// Day 4 with a bug that only shows up once there are three or more pairs.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 4;
+
+        type Input = <Day04 as Solution>::Input;
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Day04::parse(input)
+        }
+
+        fn part1(input: &Self::Input) -> u32 {
+            Day04::part1(input)
+        }
+
+        fn part2(input: &Self::Input) -> u32 {
+            Day04::part2(input) + (input.len() >= 3) as u32
+        }
+    }
+
+    #[test]
+    fn test_compare_minimizes() {
+        let puzzle = Puzzle::of::<Broken>();
+        let divergence = check(&puzzle, 40, 0..1).unwrap_err();
+        assert_eq!(divergence.part, 2);
+        assert_eq!(divergence.original_lines, 40);
+        assert_eq!(divergence.input.lines().count(), 3);
+        let [_, expected] = reference::solve(4, &divergence.input).unwrap();
+        assert_eq!(divergence.expected, expected);
+        assert!(divergence.to_string().starts_with("day 4 part 2: expected"));
+    }
+
+    /// Day 4 with a parser that wrongly rejects pairs of equal ranges.
+    struct Strict;
+
+    impl Solution for Strict {
+        const DAY: u8 = 4;
+
+        type Input = <Day04 as Solution>::Input;
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            match input.lines().position(|line| {
+                let (first, second) = line.split_once(',').unwrap_or_default();
+                first == second
+            }) {
+                Some(idx) => Err(ParseError::new(idx + 1, 1, "two different ranges")),
+                None => Day04::parse(input),
+            }
+        }
+
+        fn part1(input: &Self::Input) -> u32 {
+            Day04::part1(input)
+        }
+
+        fn part2(input: &Self::Input) -> u32 {
+            Day04::part2(input)
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_rejected_input() {
+        let puzzle = Puzzle::of::<Strict>();
+        let divergence = compare(&puzzle, "1-2,3-4\n5-6,5-6\n7-8,1-9").unwrap();
+        assert_eq!(divergence.part, 1);
+        assert_eq!(divergence.input, "5-6,5-6");
+        assert!(matches!(&divergence.actual, Err(failure) if failure.starts_with("a parse error")));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let input = "a\nb\nc\nd\ne\nf";
+        assert_eq!(
+            minimize(input, |s| s.contains('b') && s.contains('e')),
+            "b\ne"
+        );
+        assert_eq!(
+            minimize("xaybz", |s| s.contains('a') && s.contains('z')),
+            "az"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::fmt;
+
+use peg::{error, str::LineCol};
+
+/// A malformed puzzle input, located by 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Formats the error followed by the offending line of `input` with a caret under the
+    /// column.
+    pub fn render(&self, input: &str) -> String {
+        let mut result = format!("error: {}\n", self);
+        if let Some(line) = self
+            .line
+            .checked_sub(1)
+            .and_then(|idx| input.lines().nth(idx))
+        {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let caret = " ".repeat(self.column.saturating_sub(1));
+            result += &format!("{} |\n", gutter);
+            result += &format!("{} | {}\n", self.line, line);
+            result += &format!("{} | {}^\n", gutter, caret);
+        }
+        result
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<error::ParseError<LineCol>> for ParseError {
+    fn from(err: error::ParseError<LineCol>) -> Self {
+        Self::new(
+            err.location.line,
+            err.location.column,
+            err.expected.to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(2, 3, "a digit");
+        assert_eq!(err.to_string(), "<input>:2:3: expected a digit");
+        let err = err.with_file("data/day_08.txt");
+        assert_eq!(err.to_string(), "data/day_08.txt:2:3: expected a digit");
+    }
+
+    #[test]
+    fn test_render() {
+        let err = ParseError::new(2, 3, "a digit");
+        assert_eq!(
+            err.render("123\n45x\n678"),
+            "error: <input>:2:3: expected a digit\n  |\n2 | 45x\n  |   ^\n"
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::{Client, Error},
+    input,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The day's input under `data/`, if it has already been downloaded. Empty placeholders
+/// left by `new-day` don't count.
+pub fn cached(root: &Path, day: u8) -> Option<PathBuf> {
+    let path = root.join(input::data_path(day));
+    let downloaded = fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0);
+    downloaded.then_some(path)
+}
+
+/// Downloads a day's input into `data/` unless it is already cached there.
+pub fn fetch(client: &Client, root: &Path, day: u8) -> Result<Fetched, Error> {
+    if let Some(path) = cached(root, day) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let path = root.join(input::data_path(day));
+    let input = client.input(day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, normalize(&input))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Converts to the CRLF line endings without a trailing newline that `.gitattributes` gives
+/// the checked-in `data/` files.
+pub fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .replace('\n', "\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{temp_dir, MockServer};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\n2\n\n3\n"), "1\r\n2\r\n\r\n3");
+        assert_eq!(normalize("1\r\n2\r\n"), "1\r\n2");
+        assert_eq!(normalize("abc"), "abc");
+    }
+
+    #[test]
+    fn test_fetch() {
+        let root = temp_dir("fetch");
+        let server = MockServer::start(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(&server.url, "abc123");
+        let path = root.join("data/day_01.txt");
+
+        assert_eq!(
+            fetch(&client, &root, 1).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\r\n2000");
+        assert_eq!(fetch(&client, &root, 1).unwrap(), Fetched::Cached(path));
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
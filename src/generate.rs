@@ -0,0 +1,276 @@
+//! Synthetic puzzle inputs for stress tests and benchmarks. Every generator produces an
+//! input that parses and that both parts can solve; `size` scales the input roughly linearly
+//! and is clamped to whatever minimum the puzzle needs.
+
+use std::io::{self, Write};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A reproducible input for `day` built from `seed`, or `None` if there is no generator for
+/// that day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let input = match day {
+        1 => day_01(rng, size),
+        2 => day_02(rng, size),
+        3 => day_03(rng, size),
+        4 => day_04(rng, size),
+        5 => day_05(rng, size),
+        6 => day_06(rng, size),
+        7 => day_07(rng, size),
+        8 => day_08(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// `size` elves, each carrying a handful of snacks.
+pub fn day_01(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = Vec::new();
+    write_day_01(&mut input, rng, size).unwrap();
+    String::from_utf8(input).unwrap()
+}
+
+/// Writes the same input as [`day_01`] without building it in memory first, for inventories
+/// too big to hold.
+pub fn write_day_01(out: &mut impl Write, rng: &mut impl Rng, size: usize) -> io::Result<()> {
+    for elf in 0..size.max(3) {
+        if elf > 0 {
+            out.write_all(b"\n\n")?;
+        }
+        for item in 0..rng.gen_range(1..=15) {
+            if item > 0 {
+                out.write_all(b"\n")?;
+            }
+            write!(out, "{}", rng.gen_range(1000..=60000))?;
+        }
+    }
+    Ok(())
+}
+
+/// A strategy guide with `size` rounds.
+pub fn day_02(rng: &mut impl Rng, size: usize) -> String {
+    let rounds: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let theirs = *b"ABC".choose(rng).unwrap() as char;
+            let mine = *b"XYZ".choose(rng).unwrap() as char;
+            format!("{} {}", theirs, mine)
+        })
+        .collect();
+    rounds.join("\n")
+}
+
+/// `size` groups of three rucksacks. Each rucksack has exactly one item in both compartments
+/// and each group has exactly one badge carried by all three elves.
+pub fn day_03(rng: &mut impl Rng, size: usize) -> String {
+    let mut rucksacks = Vec::new();
+    for _ in 0..size.max(1) {
+        let mut letters = LETTERS.to_vec();
+        letters.shuffle(rng);
+        let badge = letters.pop().unwrap();
+        // The elves draw from disjoint pools, so the badge is the only item they share.
+        for pool in letters.chunks(letters.len() / 3).take(3) {
+            let (shared, rest) = pool.split_first().unwrap();
+            let (left, right) = rest.split_at(rest.len() / 2);
+            let len = rng.gen_range(2..=16);
+
+            let mut first = vec![*shared, badge];
+            first.extend((2..len).map(|_| *left.choose(rng).unwrap()));
+            first.shuffle(rng);
+            let mut second = vec![*shared];
+            second.extend((1..len).map(|_| *right.choose(rng).unwrap()));
+            second.shuffle(rng);
+
+            first.extend(second);
+            rucksacks.push(String::from_utf8(first).unwrap());
+        }
+    }
+    rucksacks.join("\n")
+}
+
+/// `size` pairs of section assignments.
+pub fn day_04(rng: &mut impl Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.gen_range(1..=99);
+        format!("{}-{}", start, rng.gen_range(start..=99))
+    };
+    let pairs: Vec<String> = (0..size.max(1))
+        .map(|_| format!("{},{}", range(), range()))
+        .collect();
+    pairs.join("\n")
+}
+
+/// A drawing of up to nine stacks followed by `size` moves. No move empties a stack, so
+/// every stack has a crate on top at the end.
+pub fn day_05(rng: &mut impl Rng, size: usize) -> String {
+    let num_stacks = rng.gen_range(2..=9);
+    let mut stacks: Vec<Vec<u8>> = (0..num_stacks)
+        .map(|_| {
+            (0..rng.gen_range(2..=8))
+                .map(|_| rng.gen_range(b'A'..=b'Z'))
+                .collect()
+        })
+        .collect();
+
+    let height = stacks.iter().map(Vec::len).max().unwrap();
+    let mut lines: Vec<String> = (0..height)
+        .rev()
+        .map(|level| {
+            let slots: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", *c as char),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            slots.join(" ")
+        })
+        .collect();
+    let index: Vec<String> = (1..=num_stacks).map(|n| format!(" {} ", n)).collect();
+    lines.push(index.join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size.max(1) {
+        let sources: Vec<usize> = (0..num_stacks).filter(|&i| stacks[i].len() > 1).collect();
+        let src = *sources.choose(rng).unwrap();
+        let dst = (src + rng.gen_range(1..num_stacks)) % num_stacks;
+        let quantity = rng.gen_range(1..stacks[src].len());
+        let keep = stacks[src].len() - quantity;
+        let moved = stacks[src].split_off(keep);
+        stacks[dst].extend(moved);
+        lines.push(format!("move {} from {} to {}", quantity, src + 1, dst + 1));
+    }
+    lines.join("\n")
+}
+
+/// A datastream of about `size` characters. A packet marker appears about halfway through
+/// and a message marker is planted near the end.
+pub fn day_06(rng: &mut impl Rng, size: usize) -> String {
+    let half = size.max(32) / 2;
+    let mut letters = LETTERS[..26].to_vec();
+    letters.shuffle(rng);
+    let mut stream = Vec::new();
+    // Windows drawn from three letters can't hold four distinct ones, and windows drawn
+    // from thirteen can't hold fourteen.
+    stream.extend((0..half).map(|_| *letters[..3].choose(rng).unwrap()));
+    stream.extend((0..half - 14).map(|_| *letters[..13].choose(rng).unwrap()));
+    stream.extend(&letters[..14]);
+    stream.extend((0..rng.gen_range(0..=10)).map(|_| *letters.choose(rng).unwrap()));
+    String::from_utf8(stream).unwrap()
+}
+
+struct Node {
+    files: Vec<(String, usize)>,
+    dirs: Vec<(String, usize)>,
+}
+
+/// A terminal session exploring a random tree of `size` directories with a total size that
+/// leaves something to delete in part 2.
+pub fn day_07(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut nodes = vec![Node {
+        files: Vec::new(),
+        dirs: Vec::new(),
+    }];
+    for n in 1..=size {
+        let parent = rng.gen_range(0..nodes.len());
+        let dir = (format!("{}{}", stem(rng), n), nodes.len());
+        nodes[parent].dirs.push(dir);
+        nodes.push(Node {
+            files: Vec::new(),
+            dirs: Vec::new(),
+        });
+    }
+    // Small files add up to at most 20000000 on top of one big file, so the disk is between
+    // 40000000 and 70000000 full and the directory holding the big file is big enough to
+    // free the space needed.
+    let max_file = 4_000_000 / nodes.len();
+    for node in nodes.iter_mut() {
+        for n in 0..rng.gen_range(1..=5) {
+            let extension = ["txt", "dat", "log"].choose(rng).unwrap();
+            let file_name = format!("{}{}.{}", stem(rng), n, extension);
+            node.files.push((file_name, rng.gen_range(1..=max_file)));
+        }
+    }
+    let big = nodes[0].dirs[0].1;
+    nodes[big].files.push(("big.bin".to_string(), 45_000_000));
+
+    let mut lines = vec!["$ cd /".to_string()];
+    transcript(&nodes, 0, &mut lines);
+    lines.join("\n")
+}
+
+/// A short random name; callers add a suffix to keep names unique within a directory.
+fn stem(rng: &mut impl Rng) -> String {
+    (0..rng.gen_range(1..=6))
+        .map(|_| rng.gen_range('a'..='z'))
+        .collect()
+}
+
+fn transcript(nodes: &[Node], node: usize, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    for (name, _) in nodes[node].dirs.iter() {
+        lines.push(format!("dir {}", name));
+    }
+    for (name, size) in nodes[node].files.iter() {
+        lines.push(format!("{} {}", size, name));
+    }
+    for (name, child) in nodes[node].dirs.iter() {
+        lines.push(format!("$ cd {}", name));
+        transcript(nodes, *child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// A `size` by `size` grid of tree heights.
+pub fn day_08(rng: &mut impl Rng, size: usize) -> String {
+    let size = size.max(1);
+    let rows: Vec<String> = (0..size)
+        .map(|_| (0..size).map(|_| rng.gen_range('0'..='9')).collect())
+        .collect();
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for puzzle in days::all() {
+            for (size, seed) in [(0, 1), (1, 2), (10, 3), (200, 4)] {
+                let input = generate(puzzle.day, size, seed).unwrap();
+                let parsed = match puzzle.parse(&input) {
+                    Ok(parsed) => parsed,
+                    Err(err) => panic!("day {}: {}", puzzle.day, err.render(&input)),
+                };
+                parsed.part1();
+                parsed.part2();
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_is_reproducible() {
+        assert_eq!(generate(5, 20, 7), generate(5, 20, 7));
+        assert_ne!(generate(5, 20, 7), generate(5, 20, 8));
+        assert_eq!(generate(25, 20, 7), None);
+    }
+
+    #[test]
+    fn test_day_03_has_one_shared_item() {
+        use crate::{days::day_03::Day03, Solution};
+
+        let input = generate(3, 50, 11).unwrap();
+        for rucksack in Day03::parse(&input).unwrap() {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            let mut shared: Vec<char> = first.chars().filter(|c| second.contains(*c)).collect();
+            shared.sort();
+            shared.dedup();
+            assert_eq!(shared.len(), 1, "{}", rucksack);
+        }
+    }
+}
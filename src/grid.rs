@@ -0,0 +1,338 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The `(row, col)` change from taking one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid cells must fill the grid");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows of equal length, or returns `None` if they are ragged.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    /// Parses a block of lines with one cell per character, e.g. a digit grid. `expected`
+    /// describes a valid character for error messages.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (idx, line) in input.trim_end().lines().enumerate() {
+            let mut line_width = 0;
+            for (column, c) in line.chars().enumerate() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(ParseError::new(idx + 1, column + 1, expected)),
+                }
+                line_width += 1;
+            }
+            if line_width == 0 {
+                return Err(ParseError::new(idx + 1, 1, expected));
+            }
+            let width = *width.get_or_insert(line_width);
+            if line_width != width {
+                let expected = format!("a row of {} cells", width);
+                return Err(ParseError::new(
+                    idx + 1,
+                    line_width.min(width) + 1,
+                    expected,
+                ));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) => Ok(Self::new(width, height, cells)),
+            None => Err(ParseError::new(1, 1, expected)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn is_edge(&self, point: Point) -> bool {
+        point.row == 0
+            || point.col == 0
+            || point.row + 1 == self.height
+            || point.col + 1 == self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.col])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.row * self.width + point.col])
+        } else {
+            None
+        }
+    }
+
+    /// The neighbouring point in `direction`, if it is inside the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        let (row, col) = direction.offset();
+        let next = Point::new(
+            point.row.checked_add_signed(row)?,
+            point.col.checked_add_signed(col)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The cells from `start` (exclusive) to the edge of the grid in `direction`.
+    pub fn ray(&self, start: Point, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            point: start,
+            direction,
+        }
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        self.cells[row * self.width..(row + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            col < self.width,
+            "column {} out of range for width {}",
+            col,
+            self.width
+        );
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+    direction: Direction,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.point = self.grid.step(self.point, self.direction)?;
+        Some((self.point, &self.grid[self.point]))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("point outside grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("point outside grid")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        &self[Point::new(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self[Point::new(row, col)]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.height {
+            for cell in self.row(row) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits("123\r\n456\r\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid[Point::new(0, 1)], 2);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let parse = |input| Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(parse("12\n3x"), ParseError::new(2, 2, "a digit"));
+        assert_eq!(parse("12\n3"), ParseError::new(2, 2, "a row of 2 cells"));
+        assert_eq!(parse(""), ParseError::new(1, 1, "a digit"));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits("123\n456\n789");
+        let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors4(Point::new(2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = digits("123\n456\n789");
+        let cells = |ray: Ray<u8>| ray.map(|(_, cell)| *cell).collect::<Vec<_>>();
+        let center = Point::new(1, 1);
+        assert_eq!(cells(grid.ray(center, Direction::North)), vec![2]);
+        assert_eq!(cells(grid.ray(center, Direction::SouthEast)), vec![9]);
+        assert_eq!(
+            cells(grid.ray(Point::new(2, 0), Direction::NorthEast)),
+            vec![5, 3]
+        );
+        assert!(cells(grid.ray(Point::new(0, 0), Direction::West)).is_empty());
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.iter().nth(4), Some((center, &5)));
+        assert!(grid.is_edge(Point::new(1, 2)) && !grid.is_edge(center));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 4 out of range for width 3")]
+    fn test_column_out_of_range() {
+        digits("123\n456\n789").column(4).count();
+    }
+}
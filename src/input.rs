@@ -0,0 +1,96 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The checked-in `data/day_NN.txt` file.
+    Data,
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    /// Interprets a path argument, treating `-` as stdin.
+    pub fn from_path(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Source::Stdin
+        } else {
+            Source::File(path)
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            Source::Data => fs::read_to_string(data_path(day)),
+            Source::File(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Inline(input) => Ok(input.clone()),
+        }
+    }
+
+    /// A human-readable name for the input, used in messages.
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            Source::Data => data_path(day).display().to_string(),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => "<stdin>".to_string(),
+            Source::Inline(_) => "<inline>".to_string(),
+        }
+    }
+}
+
+pub fn data_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("data/day_{:02}.txt", day))
+}
+
+/// A stable 64-bit FNV-1a hash of the input, used to tell inputs apart in reports.
+pub fn checksum(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The path of the `n`th example from the puzzle statement, starting at 1.
+pub fn example_path(day: u8, n: usize) -> PathBuf {
+    PathBuf::from(format!("data/examples/day_{:02}_{}.txt", day, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(Source::from_path("-".into()), Source::Stdin);
+        assert_eq!(
+            Source::from_path("in.txt".into()),
+            Source::File("in.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), 0xcbf29ce484222325);
+        assert_eq!(checksum("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(checksum("1\n2"), checksum("2\n1"));
+    }
+
+    #[test]
+    fn test_read() {
+        assert_eq!(Source::Inline("1\n2".into()).read(1).unwrap(), "1\n2");
+        assert_eq!(
+            Source::Data.read(6).unwrap(),
+            Source::File(data_path(6)).read(99).unwrap()
+        );
+        assert!(Source::File("data/missing.txt".into()).read(1).is_err());
+    }
+}
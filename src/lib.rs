@@ -0,0 +1,30 @@
+pub mod answers;
+pub mod client;
+pub mod days;
+pub mod differential;
+mod error;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod reference;
+pub mod runner;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+#[cfg(test)]
+mod test_util;
+mod toml_file;
+pub mod watch;
+
+pub use error::ParseError;
+pub use solution::{Answer, Parsed, Puzzle, Solution};
+
+pub fn read_input(day: u8) -> String {
+    input::Source::Data.read(day).expect("File missing")
+}
+
+pub fn read_example(day: u8, n: usize) -> String {
+    std::fs::read_to_string(input::example_path(day, n)).expect("File missing")
+}
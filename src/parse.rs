@@ -0,0 +1,150 @@
+use std::str::FromStr;
+
+use peg::RuleResult;
+
+use crate::{grid::Grid, ParseError};
+
+/// The input's lines numbered from 1, ignoring trailing blank lines.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line))
+}
+
+/// Whether a line is empty or all whitespace.
+pub fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Runs of numbered lines separated by one or more blank lines.
+pub fn groups(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let lines: Vec<(usize, &str)> = lines(input).collect();
+    lines
+        .split(|(_, line)| is_blank(line))
+        .filter(|group| !group.is_empty())
+        .map(<[_]>::to_vec)
+        .collect()
+}
+
+/// Parses `s`, found at `line` and `column`, reporting `expected` if it is malformed.
+/// Surrounding whitespace is ignored, and errors point at the first character after it.
+pub fn number<T: FromStr>(
+    s: &str,
+    line: usize,
+    column: usize,
+    expected: &str,
+) -> Result<T, ParseError> {
+    s.trim().parse().map_err(|_| {
+        let indent = s.chars().take_while(|c| c.is_whitespace()).count();
+        ParseError::new(line, column + indent, expected)
+    })
+}
+
+/// Every integer in `s`, with an optional leading `-`, skipping everything in between.
+pub fn integers<T: FromStr>(s: &str) -> Result<Vec<T>, T::Err> {
+    let mut result = Vec::new();
+    let mut pos = 0;
+    while pos < s.len() {
+        match signed_len(&s[pos..]) {
+            0 => pos += s[pos..].chars().next().map_or(1, char::len_utf8),
+            len => {
+                result.push(s[pos..pos + len].parse()?);
+                pos += len;
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// A grid with one digit per character.
+pub fn digit_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+}
+
+/// Matches a run of digits in a `peg` grammar, failing if it does not fit in `T`:
+///
+/// ```text
+/// rule number() -> u32
+///     = quiet!{ #{parse::unsigned} } / expected!("a number")
+/// ```
+pub fn unsigned<T: FromStr>(input: &str, pos: usize) -> RuleResult<T> {
+    matched(input, pos, digits_len(&input[pos..]))
+}
+
+/// Like [`unsigned`], but also accepts a leading `-`.
+pub fn signed<T: FromStr>(input: &str, pos: usize) -> RuleResult<T> {
+    matched(input, pos, signed_len(&input[pos..]))
+}
+
+fn matched<T: FromStr>(input: &str, pos: usize, len: usize) -> RuleResult<T> {
+    match input[pos..pos + len].parse() {
+        Ok(n) if len > 0 => RuleResult::Matched(pos + len, n),
+        _ => RuleResult::Failed,
+    }
+}
+
+fn digits_len(s: &str) -> usize {
+    s.bytes().take_while(u8::is_ascii_digit).count()
+}
+
+fn signed_len(s: &str) -> usize {
+    match s.strip_prefix('-') {
+        Some(rest) if digits_len(rest) > 0 => 1 + digits_len(rest),
+        _ => digits_len(s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_and_groups() {
+        let input = "1\r\n2\r\n\r\n3\r\n\r\n";
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            [(1, "1"), (2, "2"), (3, ""), (4, "3")]
+        );
+        assert_eq!(groups(input), [vec![(1, "1"), (2, "2")], vec![(4, "3")]]);
+        assert_eq!(groups(" \n1\n\n \t\n\n2"), [vec![(2, "1")], vec![(6, "2")]]);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>(" 12\r", 3, 1, "a number"), Ok(12));
+        let err = number::<u32>("  x", 3, 5, "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 7));
+    }
+
+    #[test]
+    fn test_integers() {
+        let numbers: Vec<i32> = integers("move 3 from -1 to 10-2, x-").unwrap();
+        assert_eq!(numbers, [3, -1, 10, -2]);
+        assert!(integers::<u8>("300").is_err());
+        assert!(integers::<u32>("no numbers").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_peg_rules() {
+        peg::parser! {
+            grammar numbers() for str {
+                pub rule pair() -> (u8, i64)
+                    = a:unsigned() "," b:signed() { (a, b) }
+
+                rule unsigned() -> u8
+                    = quiet!{ #{super::unsigned} } / expected!("u8")
+
+                rule signed() -> i64
+                    = quiet!{ #{super::signed} } / expected!("i64")
+            }
+        }
+
+        assert_eq!(numbers::pair("12,-34"), Ok((12, -34)));
+        assert_eq!(numbers::pair("255,7"), Ok((255, 7)));
+        let err = numbers::pair("256,7").unwrap_err();
+        assert_eq!(err.expected.to_string(), "u8");
+        assert!(numbers::pair("-1,7").is_err());
+    }
+}
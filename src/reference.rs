@@ -0,0 +1,285 @@
+//! Deliberately naive solutions, written straight from the puzzle statements with no
+//! attention to speed, for differential testing against the real ones. Each returns both
+//! answers, or `None` if the input isn't one it understands.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::Answer;
+
+pub fn solve(day: u8, input: &str) -> Option<[Answer; 2]> {
+    match day {
+        1 => day_01(input),
+        2 => day_02(input),
+        3 => day_03(input),
+        4 => day_04(input),
+        5 => day_05(input),
+        6 => day_06(input),
+        7 => day_07(input),
+        8 => day_08(input),
+        _ => None,
+    }
+}
+
+fn lines(input: &str) -> Vec<&str> {
+    input.trim_end().lines().map(str::trim_end).collect()
+}
+
+pub fn day_01(input: &str) -> Option<[Answer; 2]> {
+    let mut totals = Vec::new();
+    let mut current = None;
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() {
+            totals.extend(current.take());
+        } else {
+            let total: u64 = current.unwrap_or(0);
+            current = Some(total.checked_add(line.parse().ok()?)?);
+        }
+    }
+    totals.extend(current);
+    totals.sort_by(|a, b| b.cmp(a));
+    let most = totals.first().copied().unwrap_or(0);
+    let top_three = totals.iter().take(3).sum::<u64>();
+    Some([Answer::Number(most), Answer::Number(top_three)])
+}
+
+pub fn day_02(input: &str) -> Option<[Answer; 2]> {
+    let (mut part1, mut part2) = (0, 0);
+    for line in lines(input) {
+        // Scores for every round, copied from the statement's rules.
+        part1 += match line {
+            "A X" => 1 + 3,
+            "A Y" => 2 + 6,
+            "A Z" => 3,
+            "B X" => 1,
+            "B Y" => 2 + 3,
+            "B Z" => 3 + 6,
+            "C X" => 1 + 6,
+            "C Y" => 2,
+            "C Z" => 3 + 3,
+            _ => return None,
+        };
+        part2 += match line {
+            "A X" => 3,
+            "A Y" => 1 + 3,
+            "A Z" => 2 + 6,
+            "B X" => 1,
+            "B Y" => 2 + 3,
+            "B Z" => 3 + 6,
+            "C X" => 2,
+            "C Y" => 3 + 3,
+            "C Z" => 1 + 6,
+            _ => return None,
+        };
+    }
+    Some([Answer::Number(part1), Answer::Number(part2)])
+}
+
+fn priority(item: char) -> Option<u64> {
+    let letters = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    letters.find(item).map(|idx| idx as u64 + 1)
+}
+
+pub fn day_03(input: &str) -> Option<[Answer; 2]> {
+    let rucksacks = lines(input);
+
+    let mut part1 = 0;
+    for rucksack in rucksacks.iter() {
+        let (first, second) = rucksack.split_at(rucksack.len() / 2);
+        let shared = first.chars().find(|item| second.contains(*item))?;
+        part1 += priority(shared)?;
+    }
+
+    let mut part2 = 0;
+    for group in rucksacks.chunks(3) {
+        let badge = group[0]
+            .chars()
+            .find(|item| group.iter().all(|rucksack| rucksack.contains(*item)))?;
+        part2 += priority(badge)?;
+    }
+    Some([Answer::Number(part1), Answer::Number(part2)])
+}
+
+pub fn day_04(input: &str) -> Option<[Answer; 2]> {
+    let (mut part1, mut part2) = (0, 0);
+    for line in lines(input) {
+        let mut sections = Vec::new();
+        for range in line.split(',') {
+            let (start, end) = range.split_once('-')?;
+            let range: HashSet<u64> = (start.parse().ok()?..=end.parse().ok()?).collect();
+            sections.push(range);
+        }
+        let [first, second] = <[HashSet<u64>; 2]>::try_from(sections).ok()?;
+        if first.is_subset(&second) || second.is_subset(&first) {
+            part1 += 1;
+        }
+        if !first.is_disjoint(&second) {
+            part2 += 1;
+        }
+    }
+    Some([Answer::Number(part1), Answer::Number(part2)])
+}
+
+pub fn day_05(input: &str) -> Option<[Answer; 2]> {
+    let lines = lines(input);
+    let blank = lines.iter().position(|line| line.is_empty())?;
+    let (index, drawing) = lines[..blank].split_last()?;
+
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); index.split_whitespace().count()];
+    for line in drawing.iter().rev() {
+        for (n, stack) in stacks.iter_mut().enumerate() {
+            match line.chars().nth(1 + 4 * n) {
+                Some(' ') | None => {}
+                Some(c) => stack.push(c),
+            }
+        }
+    }
+
+    let mut one_at_a_time = stacks.clone();
+    let mut all_at_once = stacks;
+    for line in lines[blank + 1..].iter() {
+        let words: Vec<&str> = line.split(' ').collect();
+        let [_, quantity, _, src, _, dst] = words[..] else {
+            return None;
+        };
+        let quantity: usize = quantity.parse().ok()?;
+        let src = src.parse::<usize>().ok()?.checked_sub(1)?;
+        let dst = dst.parse::<usize>().ok()?.checked_sub(1)?;
+
+        for _ in 0..quantity {
+            let c = one_at_a_time.get_mut(src)?.pop()?;
+            one_at_a_time.get_mut(dst)?.push(c);
+        }
+        let height = all_at_once.get(src)?.len().checked_sub(quantity)?;
+        let moved: Vec<char> = all_at_once[src].drain(height..).collect();
+        all_at_once.get_mut(dst)?.extend(moved);
+    }
+
+    let tops = |stacks: &[Vec<char>]| -> Option<String> {
+        stacks.iter().map(|stack| stack.last()).collect()
+    };
+    Some([
+        Answer::Text(tops(&one_at_a_time)?),
+        Answer::Text(tops(&all_at_once)?),
+    ])
+}
+
+pub fn day_06(input: &str) -> Option<[Answer; 2]> {
+    let chars: Vec<char> = input.trim().chars().collect();
+    let marker = |len: usize| {
+        (len..=chars.len())
+            .find(|&end| chars[end - len..end].iter().collect::<HashSet<_>>().len() == len)
+            .map(|end| Answer::Number(end as u64))
+    };
+    Some([marker(4)?, marker(14)?])
+}
+
+pub fn day_07(input: &str) -> Option<[Answer; 2]> {
+    let mut cwd: Vec<&str> = Vec::new();
+    let mut dirs: HashSet<Vec<&str>> = HashSet::from([Vec::new()]);
+    let mut files: HashMap<Vec<&str>, u64> = HashMap::new();
+    for line in lines(input) {
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => cwd.clear(),
+            ["$", "cd", ".."] => {
+                cwd.pop()?;
+            }
+            ["$", "cd", name] => {
+                cwd.push(name);
+                if !dirs.contains(&cwd) {
+                    return None;
+                }
+            }
+            ["$", "ls"] => {}
+            ["dir", name] => {
+                dirs.insert([&cwd[..], &[name][..]].concat());
+            }
+            [size, name] => {
+                files.insert([&cwd[..], &[name][..]].concat(), size.parse().ok()?);
+            }
+            _ => return None,
+        }
+    }
+
+    let sizes: Vec<u64> = dirs
+        .iter()
+        .map(|dir| {
+            files
+                .iter()
+                .filter(|(path, _)| path.starts_with(dir))
+                .map(|(_, size)| size)
+                .sum()
+        })
+        .collect();
+    let used: u64 = files.values().sum();
+    let needed = (used + 30000000).checked_sub(70000000)?;
+
+    let part1 = sizes.iter().filter(|&&size| size <= 100000).sum();
+    let part2 = *sizes.iter().filter(|&&size| size >= needed).min()?;
+    Some([Answer::Number(part1), Answer::Number(part2)])
+}
+
+pub fn day_08(input: &str) -> Option<[Answer; 2]> {
+    let grid: Vec<Vec<u32>> = lines(input)
+        .iter()
+        .map(|line| line.chars().map(|c| c.to_digit(10)).collect())
+        .collect::<Option<_>>()?;
+    let height = grid.len();
+    let width = grid.first()?.len();
+    if grid.iter().any(|row| row.len() != width) {
+        return None;
+    }
+
+    let mut visible = 0;
+    let mut best = 0;
+    for row in 0..height {
+        for col in 0..width {
+            let tree = grid[row][col];
+            let views: [Vec<u32>; 4] = [
+                (0..row).rev().map(|r| grid[r][col]).collect(),
+                (row + 1..height).map(|r| grid[r][col]).collect(),
+                (0..col).rev().map(|c| grid[row][c]).collect(),
+                (col + 1..width).map(|c| grid[row][c]).collect(),
+            ];
+            if views
+                .iter()
+                .any(|view| view.iter().all(|&other| other < tree))
+            {
+                visible += 1;
+            }
+            let score: u64 = views
+                .iter()
+                .map(|view| match view.iter().position(|&other| other >= tree) {
+                    Some(blocker) => blocker as u64 + 1,
+                    None => view.len() as u64,
+                })
+                .product();
+            best = best.max(score);
+        }
+    }
+    Some([Answer::Number(visible), Answer::Number(best)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let expected: [(u8, usize, Answer, Answer); 10] = [
+            (1, 1, 24000u32.into(), 45000u32.into()),
+            (2, 1, 15u32.into(), 12u32.into()),
+            (3, 1, 157u32.into(), 70u32.into()),
+            (4, 1, 2u32.into(), 4u32.into()),
+            (5, 1, "CMZ".to_string().into(), "MCD".to_string().into()),
+            (6, 1, 7u32.into(), 19u32.into()),
+            (6, 2, 5u32.into(), 23u32.into()),
+            (6, 3, 6u32.into(), 23u32.into()),
+            (7, 1, 95437u32.into(), 24933642u32.into()),
+            (8, 1, 21u32.into(), 8u32.into()),
+        ];
+        for (day, n, part1, part2) in expected {
+            let input = crate::read_example(day, n);
+            assert_eq!(solve(day, &input), Some([part1, part2]), "day {}", day);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    cmp::Reverse,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{answers::Verdict, input, Answer, ParseError, Puzzle};
+
+/// A set of days given on the command line, e.g. `5`, `1-4` or `1,3,6-8`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(pub Vec<u8>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+        for item in s.split(',') {
+            let (start, end) = match item.split_once('-') {
+                Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+                None => (parse_day(item)?, parse_day(item)?),
+            };
+            if start > end {
+                return Err(format!("empty day range: {}", item));
+            }
+            days.extend(start..=end);
+        }
+        days.sort();
+        days.dedup();
+        Ok(Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day: {:?}", s)),
+    }
+}
+
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<Answer, String>,
+    pub parse_time: Duration,
+    pub elapsed: Duration,
+    pub input_checksum: u64,
+    pub verdict: Option<Verdict>,
+}
+
+impl Row {
+    pub fn failed(&self) -> bool {
+        self.result.is_err() || matches!(self.verdict, Some(Verdict::Fail { .. }))
+    }
+}
+
+/// Runs the requested parts of a puzzle, catching panics so that one failing day does not
+/// stop the others. Every row carries the day's parse time alongside the time for its own part.
+pub fn run(puzzle: &Puzzle, input: &str, parts: &[u8]) -> Result<Vec<Row>, ParseError> {
+    let start = Instant::now();
+    let parsed = match panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse(input))) {
+        Ok(parsed) => Ok(parsed?),
+        Err(payload) => Err(panic_message(payload)),
+    };
+    let parse_time = start.elapsed();
+    let input_checksum = input::checksum(input);
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let result = match &parsed {
+                Ok(parsed) => panic::catch_unwind(AssertUnwindSafe(|| match part {
+                    1 => parsed.part1(),
+                    _ => parsed.part2(),
+                }))
+                .map_err(panic_message),
+                Err(message) => Err(message.clone()),
+            };
+            Row {
+                day: puzzle.day,
+                part,
+                result,
+                parse_time,
+                elapsed: start.elapsed(),
+                input_checksum,
+                verdict: None,
+            }
+        })
+        .collect())
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+pub struct Table<'a>(pub &'a [Row]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answers: Vec<String> = self
+            .0
+            .iter()
+            .map(|row| match &row.result {
+                Ok(answer) => answer.to_string(),
+                Err(message) => format!("PANIC: {}", message),
+            })
+            .collect();
+        let width = answers.iter().map(String::len).max().unwrap_or(0).max(6);
+
+        writeln!(f, "Day  Part  {:<width$}  {:<10}  Status", "Answer", "Time")?;
+        for (row, answer) in self.0.iter().zip(answers.iter()) {
+            let elapsed = format!("{:.2?}", row.elapsed);
+            let verdict = match &row.verdict {
+                Some(verdict) => verdict.to_string(),
+                None => "-".to_string(),
+            };
+            writeln!(
+                f,
+                "{:>3}  {:>4}  {:<width$}  {:<10}  {}",
+                row.day, row.part, answer, elapsed, verdict
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A row flattened for machine-readable output.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    error: Option<&'a str>,
+    parse_ns: u64,
+    elapsed_ns: u64,
+    input_checksum: String,
+    status: Option<String>,
+}
+
+impl<'a> From<&'a Row> for Record<'a> {
+    fn from(row: &'a Row) -> Self {
+        Self {
+            day: row.day,
+            part: row.part,
+            answer: row.result.as_ref().ok(),
+            error: row.result.as_ref().err().map(String::as_str),
+            parse_ns: row.parse_time.as_nanos() as u64,
+            elapsed_ns: row.elapsed.as_nanos() as u64,
+            input_checksum: format!("{:016x}", row.input_checksum),
+            status: row.verdict.as_ref().map(Verdict::to_string),
+        }
+    }
+}
+
+pub struct Json<'a>(pub &'a [Row]);
+
+impl fmt::Display for Json<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let records: Vec<Record> = self.0.iter().map(Record::from).collect();
+        let json = serde_json::to_string_pretty(&records).map_err(|_| fmt::Error)?;
+        writeln!(f, "{}", json)
+    }
+}
+
+pub struct Csv<'a>(pub &'a [Row]);
+
+impl fmt::Display for Csv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "day,part,answer,error,parse_ns,elapsed_ns,input_checksum,status"
+        )?;
+        for row in self.0.iter() {
+            let record = Record::from(row);
+            // Text answers are always quoted so they can be told apart from numbers.
+            let answer = match record.answer {
+                Some(Answer::Number(n)) => n.to_string(),
+                Some(Answer::Text(s)) => quote(s),
+                None => String::new(),
+            };
+            writeln!(
+                f,
+                "{},{},{},{},{},{},{},{}",
+                record.day,
+                record.part,
+                answer,
+                record.error.map(quote).unwrap_or_default(),
+                record.parse_ns,
+                record.elapsed_ns,
+                record.input_checksum,
+                record.status.as_deref().map(quote).unwrap_or_default()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// Per-day timings, slowest day first, followed by the summed and wall-clock totals.
+pub struct Summary<'a>(pub &'a [Row], pub Duration);
+
+struct DayTiming {
+    day: u8,
+    parse: Duration,
+    part1: Option<Duration>,
+    part2: Option<Duration>,
+}
+
+impl DayTiming {
+    fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+fn timings(rows: &[Row]) -> Vec<DayTiming> {
+    let mut timings: Vec<DayTiming> = Vec::new();
+    for row in rows {
+        if timings.last().map(|timing| timing.day) != Some(row.day) {
+            timings.push(DayTiming {
+                day: row.day,
+                parse: row.parse_time,
+                part1: None,
+                part2: None,
+            });
+        }
+        let timing = timings.last_mut().unwrap();
+        match row.part {
+            1 => timing.part1 = Some(row.elapsed),
+            _ => timing.part2 = Some(row.elapsed),
+        }
+    }
+    // Ties keep day order, so the summary is stable between runs with equal timings.
+    timings.sort_by_key(|timing| Reverse(timing.total()));
+    timings
+}
+
+impl fmt::Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Summary(rows, wall) = self;
+        let show = |time: Option<Duration>| match time {
+            Some(time) => format!("{:.2?}", time),
+            None => "-".to_string(),
+        };
+
+        let timings = timings(rows);
+        writeln!(
+            f,
+            "Day  {:<10}  {:<10}  {:<10}  Total",
+            "Parse", "Part 1", "Part 2"
+        )?;
+        for timing in timings.iter() {
+            writeln!(
+                f,
+                "{:>3}  {:<10}  {:<10}  {:<10}  {:.2?}",
+                timing.day,
+                show(Some(timing.parse)),
+                show(timing.part1),
+                show(timing.part2),
+                timing.total()
+            )?;
+        }
+        let total: Duration = timings.iter().map(DayTiming::total).sum();
+        writeln!(f, "Total {:.2?} ({:.2?} wall clock)", total, wall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!("5".parse(), Ok(Days(vec![5])));
+        assert_eq!("1-4".parse(), Ok(Days(vec![1, 2, 3, 4])));
+        assert_eq!("6-8,1,3".parse(), Ok(Days(vec![1, 3, 6, 7, 8])));
+        assert!("4-2".parse::<Days>().is_err());
+        assert!("0".parse::<Days>().is_err());
+        assert!("x".parse::<Days>().is_err());
+    }
+
+    struct Panics;
+
+    impl crate::Solution for Panics {
+        const DAY: u8 = 6;
+
+        type Input = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> u32 {
+            panic!("part 1")
+        }
+
+        fn part2(_: &()) -> u32 {
+            panic!("part 2")
+        }
+    }
+
+    #[test]
+    fn test_run_catches_panics() {
+        let rows = run(&Puzzle::of::<Panics>(), "abc", &[1, 2]).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(Row::failed));
+        assert_eq!(rows[1].result, Err("part 2".to_string()));
+    }
+
+    #[test]
+    fn test_csv_and_json() {
+        let rows = vec![
+            Row {
+                day: 1,
+                part: 1,
+                result: Ok(Answer::Number(24000)),
+                parse_time: Duration::from_nanos(700),
+                elapsed: Duration::from_nanos(1500),
+                input_checksum: 0xabc,
+                verdict: Some(Verdict::Pass),
+            },
+            Row {
+                day: 5,
+                part: 2,
+                result: Ok(Answer::Text("MCD".to_string())),
+                parse_time: Duration::from_nanos(10),
+                elapsed: Duration::from_nanos(20),
+                input_checksum: 0xdef,
+                verdict: None,
+            },
+        ];
+        assert_eq!(
+            Csv(&rows).to_string(),
+            "day,part,answer,error,parse_ns,elapsed_ns,input_checksum,status\n\
+             1,1,24000,,700,1500,0000000000000abc,\"PASS\"\n\
+             5,2,\"MCD\",,10,20,0000000000000def,\n"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&Json(&rows).to_string()).unwrap();
+        assert_eq!(json[0]["answer"], 24000);
+        assert_eq!(json[1]["answer"], "MCD");
+        assert_eq!(json[1]["input_checksum"], "0000000000000def");
+    }
+
+    #[test]
+    fn test_summary() {
+        let row = |day, part, parse_time, elapsed| Row {
+            day,
+            part,
+            result: Ok(Answer::Number(0)),
+            parse_time: Duration::from_millis(parse_time),
+            elapsed: Duration::from_millis(elapsed),
+            input_checksum: 0,
+            verdict: None,
+        };
+        let rows = vec![
+            row(1, 1, 1, 2),
+            row(1, 2, 1, 3),
+            row(2, 1, 4, 10),
+            row(2, 2, 4, 1),
+            row(3, 2, 2, 4),
+        ];
+        assert_eq!(
+            Summary(&rows, Duration::from_millis(16)).to_string(),
+            "Day  Parse       Part 1      Part 2      Total\n\
+             \x20 2  4.00ms      10.00ms     1.00ms      15.00ms\n\
+             \x20 1  1.00ms      2.00ms      3.00ms      6.00ms\n\
+             \x20 3  2.00ms      -           4.00ms      6.00ms\n\
+             Total 27.00ms (16.00ms wall clock)\n"
+        );
+    }
+
+    #[test]
+    fn test_run_reports_parse_errors() {
+        let puzzle = crate::days::get(4).unwrap();
+        let err = run(&puzzle, "1-2,3-4\n5-6,7x8", &[1, 2]).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input;
+
+const TEMPLATE: &str = include_str!("../template.rs");
+
+/// Generates `src/days/day_NN.rs` from `template.rs`, registers it in `src/days/mod.rs` and
+/// creates empty input and example files under `data/`. Returns the paths created.
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let module = format!("day_{:02}", day);
+    let source_path = root.join("src/days").join(format!("{}.rs", module));
+    if source_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", source_path.display()),
+        ));
+    }
+
+    let struct_name = format!("Day{:02}", day);
+    let source = TEMPLATE
+        .replace("DayNN", &struct_name)
+        .replace("const DAY: u8 = N;", &format!("const DAY: u8 = {};", day));
+
+    let mod_path = root.join("src/days/mod.rs");
+    let registry = register(&fs::read_to_string(&mod_path)?, &module, &struct_name);
+
+    fs::write(&source_path, source)?;
+    fs::write(&mod_path, registry)?;
+    let mut created = vec![source_path];
+
+    for path in [input::data_path(day), input::example_path(day, 1)] {
+        let path = root.join(path);
+        if !path.exists() {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+/// Adds the module declaration and `Puzzle` entry after the last existing ones.
+fn register(registry: &str, module: &str, struct_name: &str) -> String {
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+    let last_mod = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod day_"));
+    let mod_line = format!("pub mod {};", module);
+    match last_mod {
+        Some(idx) => lines.insert(idx + 1, mod_line),
+        None => lines.insert(0, mod_line),
+    }
+
+    if let Some(idx) = lines
+        .iter()
+        .rposition(|line| line.contains("Puzzle::of::<"))
+    {
+        let indent = &lines[idx][..lines[idx].len() - lines[idx].trim_start().len()];
+        let entry = format!("{}Puzzle::of::<{}::{}>(),", indent, module, struct_name);
+        lines.insert(idx + 1, entry);
+    }
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let registry = "use crate::Puzzle;\n\npub mod day_01;\n\npub fn all() -> Vec<Puzzle> {\n    vec![\n        Puzzle::of::<day_01::Day01>(),\n    ]\n}\n";
+        assert_eq!(
+            register(registry, "day_02", "Day02"),
+            "use crate::Puzzle;\n\npub mod day_01;\npub mod day_02;\n\npub fn all() -> Vec<Puzzle> {\n    vec![\n        Puzzle::of::<day_01::Day01>(),\n        Puzzle::of::<day_02::Day02>(),\n    ]\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = crate::test_util::temp_dir("scaffold");
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), include_str!("days/mod.rs")).unwrap();
+
+        let created = new_day(&root, 9).unwrap();
+        assert_eq!(created.len(), 3);
+        let source = fs::read_to_string(root.join("src/days/day_09.rs")).unwrap();
+        assert!(source.contains("pub struct Day09;"));
+        assert!(source.contains("const DAY: u8 = 9;"));
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("pub mod day_09;"));
+        assert!(registry.contains("Puzzle::of::<day_09::Day09>(),"));
+        assert_eq!(
+            fs::read_to_string(root.join("data/day_09.txt")).unwrap(),
+            ""
+        );
+
+        let err = new_day(&root, 9).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    fmt, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{self, Client},
+    toml_file, Answer,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Wait,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wait => "submitted too recently",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::Unknown => "unrecognised response",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// One submission, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+    /// When the site will next accept an answer for this day, if it asked us to wait.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+    /// The text of the site's response.
+    pub message: String,
+}
+
+/// Every answer submitted so far, kept so that known-wrong answers are never resent.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, rename = "attempt")]
+    pub attempts: Vec<Attempt>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(Answer),
+    KnownWrong(Outcome),
+    RateLimited { seconds: u64 },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {}", answer),
+            Refusal::KnownWrong(outcome) => write!(f, "answer is known to be {}", outcome),
+            Refusal::RateLimited { seconds } => {
+                write!(f, "rate limited, try again in {}s", seconds)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Refused(Refusal),
+    Client(client::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Refused(refusal) => write!(f, "not submitting: {}", refusal),
+            Error::Client(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<client::Error> for Error {
+    fn from(err: client::Error) -> Self {
+        Error::Client(err)
+    }
+}
+
+impl History {
+    /// Loads the history, treating a missing file as empty.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        toml_file::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        toml_file::save(self, path)
+    }
+
+    /// Decides from earlier attempts whether `answer` is worth sending now.
+    pub fn check(&self, day: u8, part: u8, answer: &Answer, now: u64) -> Result<(), Refusal> {
+        let attempts = || {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.day == day && attempt.part == part)
+        };
+
+        if let Some(attempt) = attempts().find(|attempt| attempt.outcome == Outcome::Right) {
+            return Err(Refusal::AlreadySolved(attempt.answer.clone()));
+        }
+        for attempt in attempts().filter(|attempt| attempt.outcome.is_wrong()) {
+            let bounded = match (&attempt.answer, answer, attempt.outcome) {
+                (Answer::Number(wrong), Answer::Number(n), Outcome::TooHigh) => n >= wrong,
+                (Answer::Number(wrong), Answer::Number(n), Outcome::TooLow) => n <= wrong,
+                _ => false,
+            };
+            if bounded || attempt.answer == *answer {
+                return Err(Refusal::KnownWrong(attempt.outcome));
+            }
+        }
+
+        let retry_at = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day)
+            .filter_map(|attempt| attempt.retry_at)
+            .max();
+        match retry_at {
+            Some(retry_at) if retry_at > now => Err(Refusal::RateLimited {
+                seconds: retry_at - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Sends `answer` unless the history says it is pointless, and records the attempt.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    now: u64,
+) -> Result<Attempt, Error> {
+    history
+        .check(day, part, answer, now)
+        .map_err(Error::Refused)?;
+
+    let page = client.answer(day, part, &answer.to_string())?;
+    let message = article_text(&page);
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.clone(),
+        outcome: classify(&message),
+        submitted_at: now,
+        retry_at: parse_wait(&message).map(|seconds| now + seconds),
+        message,
+    };
+    history.attempts.push(attempt.clone());
+    Ok(attempt)
+}
+
+/// The text of the response's `<article>`, with tags stripped.
+fn article_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn classify(message: &str) -> Outcome {
+    if message.contains("That's the right answer") {
+        Outcome::Right
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::Wait
+    } else if message.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Outcome::TooHigh
+        } else if message.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else {
+        Outcome::Unknown
+    }
+}
+
+/// Extracts how long the site asks us to wait, from either "you have 1m 30s left to wait" or
+/// "please wait 5 minutes before trying again".
+fn parse_wait(message: &str) -> Option<u64> {
+    let message = message.to_lowercase();
+    if let Some(end) = message.find(" left to wait") {
+        let start = message[..end].rfind("you have ")? + "you have ".len();
+        return message[start..end]
+            .split_whitespace()
+            .map(|part| {
+                let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+                let n: u64 = n.parse().ok()?;
+                match unit {
+                    "h" => Some(n * 3600),
+                    "m" => Some(n * 60),
+                    "s" => Some(n),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    let start = message.find("wait ")? + "wait ".len();
+    let mut words = message[start..].split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        "minute" | "minutes" => Some(n * 60),
+        "second" | "seconds" => Some(n),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::MockServer;
+
+    const TOO_HIGH: &str = "<html><main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article></main></html>";
+    const RIGHT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>";
+
+    fn attempt(part: u8, answer: u64, outcome: Outcome) -> Attempt {
+        Attempt {
+            day: 1,
+            part,
+            answer: Answer::Number(answer),
+            outcome,
+            submitted_at: 0,
+            retry_at: None,
+            message: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify(&article_text(TOO_HIGH)), Outcome::TooHigh);
+        assert_eq!(classify(&article_text(RIGHT)), Outcome::Right);
+        assert_eq!(classify(&article_text(WAIT)), Outcome::Wait);
+        assert_eq!(
+            classify("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            classify("You don't seem to be solving the right level.  Did you already complete it?"),
+            Outcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait(&article_text(TOO_HIGH)), Some(60));
+        assert_eq!(parse_wait(&article_text(WAIT)), Some(272));
+        assert_eq!(parse_wait("You have 12s left to wait."), Some(12));
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again"),
+            Some(300)
+        );
+        assert_eq!(parse_wait(&article_text(RIGHT)), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let history = History {
+            attempts: vec![
+                attempt(1, 500, Outcome::TooHigh),
+                attempt(1, 100, Outcome::TooLow),
+                attempt(1, 250, Outcome::Wrong),
+                attempt(2, 42, Outcome::Right),
+            ],
+        };
+        let check = |part, n| history.check(1, part, &Answer::Number(n), 0);
+        assert_eq!(check(1, 600), Err(Refusal::KnownWrong(Outcome::TooHigh)));
+        assert_eq!(check(1, 100), Err(Refusal::KnownWrong(Outcome::TooLow)));
+        assert_eq!(check(1, 250), Err(Refusal::KnownWrong(Outcome::Wrong)));
+        assert_eq!(check(1, 300), Ok(()));
+        assert_eq!(
+            check(2, 43),
+            Err(Refusal::AlreadySolved(Answer::Number(42)))
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, RIGHT)]);
+        let client = Client::new(&server.url, "abc123");
+        let mut history = History::default();
+        let answer = |n| Answer::Number(n);
+
+        let first = submit(&client, &mut history, 1, 1, &answer(500), 1000).unwrap();
+        assert_eq!(first.outcome, Outcome::TooHigh);
+        assert_eq!(first.retry_at, Some(1060));
+
+        assert!(matches!(
+            submit(&client, &mut history, 1, 1, &answer(400), 1030),
+            Err(Error::Refused(Refusal::RateLimited { seconds: 30 }))
+        ));
+        assert!(matches!(
+            submit(&client, &mut history, 1, 1, &answer(500), 1100),
+            Err(Error::Refused(Refusal::KnownWrong(Outcome::TooHigh)))
+        ));
+
+        let second = submit(&client, &mut history, 1, 1, &answer(400), 1100).unwrap();
+        assert_eq!(second.outcome, Outcome::Right);
+        assert_eq!(history.attempts.len(), 2);
+        assert_eq!(server.requests().len(), 2);
+
+        let saved = toml::to_string(&history).unwrap();
+        assert_eq!(toml::from_str::<History>(&saved).unwrap(), history);
+    }
+}
@@ -0,0 +1,24 @@
+//! Loading and saving the small TOML files the runner keeps its state in.
+
+use std::{fs, io, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+pub fn parse<T: DeserializeOwned>(contents: &str) -> io::Result<T> {
+    toml::from_str(contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Loads `path`, treating a missing file as empty.
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse(&contents),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn save<T: Serialize>(value: &T, path: &Path) -> io::Result<()> {
+    let contents =
+        toml::to_string(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(path, contents)
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::SystemTime,
+};
+
+use serde::Deserialize;
+
+use crate::{input, Answer};
+
+/// The day's source file, its input under `data/` and every example fixture, relative to
+/// `root`. Fixtures are listed afresh on each call so that newly added ones get picked up.
+pub fn watched_paths(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let mut paths = vec![
+        PathBuf::from(format!("src/days/day_{:02}.rs", day)),
+        input::data_path(day),
+    ];
+    paths.extend(examples(root, day)?);
+    Ok(paths)
+}
+
+/// The example fixtures for a day, in name order.
+pub fn examples(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let prefix = format!("day_{:02}_", day);
+    let dir = input::example_path(day, 1).parent().unwrap().to_path_buf();
+    let entries = match fs::read_dir(root.join(&dir)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with(&prefix) && name.ends_with(".txt") {
+            examples.push(dir.join(name));
+        }
+    }
+    examples.sort();
+    Ok(examples)
+}
+
+/// Modification times of a set of files; a missing file is recorded as `None`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(root: &Path, paths: &[PathBuf]) -> Self {
+        Snapshot(
+            paths
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(root.join(path)).and_then(|m| m.modified());
+                    (path.clone(), modified.ok())
+                })
+                .collect(),
+        )
+    }
+
+    /// Paths that were added, removed or modified since `previous`.
+    pub fn changed<'a>(&'a self, previous: &'a Snapshot) -> Vec<&'a Path> {
+        let mut changed: Vec<&Path> = self
+            .0
+            .iter()
+            .filter(|(path, modified)| previous.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.as_path())
+            .collect();
+        changed.extend(
+            previous
+                .0
+                .keys()
+                .filter(|path| !self.0.contains_key(*path))
+                .map(PathBuf::as_path),
+        );
+        changed
+    }
+}
+
+/// The answers printed for each input and part in one round of the watch loop.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Results(BTreeMap<(String, u8), Result<Answer, String>>);
+
+#[derive(Deserialize)]
+struct Record {
+    part: u8,
+    answer: Option<Answer>,
+    error: Option<String>,
+}
+
+impl Results {
+    /// Adds the rows from the runner's `--format json` output for one input.
+    pub fn extend_from_json(&mut self, input: &str, json: &str) -> serde_json::Result<()> {
+        let records: Vec<Record> = serde_json::from_str(json)?;
+        for record in records {
+            let result = match (record.answer, record.error) {
+                (Some(answer), _) => Ok(answer),
+                (None, error) => Err(error.unwrap_or_default()),
+            };
+            self.0.insert((input.to_string(), record.part), result);
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Where the watch loop builds its own copy of the runner. Rebuilding the binary that is
+/// running the loop would fail on Windows, which locks a running executable.
+const TARGET_DIR: &str = "target/watch";
+
+fn cargo() -> Command {
+    Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+}
+
+/// Builds a copy of the runner, returning its path. Build errors go straight to stderr;
+/// `None` means the build failed.
+pub fn build(root: &Path) -> Option<PathBuf> {
+    let status = cargo()
+        .args([
+            "build",
+            "--quiet",
+            "--bin",
+            "aoc",
+            "--target-dir",
+            TARGET_DIR,
+        ])
+        .current_dir(root)
+        .status()
+        .ok()?;
+    let binary = root
+        .join(TARGET_DIR)
+        .join("debug")
+        .join(format!("aoc{}", std::env::consts::EXE_SUFFIX));
+    status.success().then_some(binary)
+}
+
+/// Runs both parts of `day` on `input` with a runner from [`build`], returning its JSON
+/// output. Parse errors go straight to stderr; `None` means nothing could be run.
+pub fn run(binary: &Path, day: u8, input: &Path) -> Option<String> {
+    let output = Command::new(binary)
+        .args(["--format", "json"])
+        .arg("--day")
+        .arg(day.to_string())
+        .arg("--input")
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    (!stdout.trim().is_empty()).then_some(stdout)
+}
+
+/// The current results, marking each answer that differs from the previous round.
+pub struct Diff<'a>(pub &'a Results, pub &'a Results);
+
+impl fmt::Display for Diff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Diff(previous, current) = self;
+        let show = |result: &Result<Answer, String>| match result {
+            Ok(answer) => answer.to_string(),
+            Err(message) => format!("PANIC: {}", message),
+        };
+
+        for (key @ (input, part), result) in current.0.iter() {
+            let change = match previous.0.get(key) {
+                None if previous.is_empty() => String::new(),
+                None => "  (new)".to_string(),
+                Some(old) if old == result => String::new(),
+                Some(old) => format!("  (was {})", show(old)),
+            };
+            writeln!(f, "{} part {}: {}{}", input, part, show(result), change)?;
+        }
+        for (input, part) in previous
+            .0
+            .keys()
+            .filter(|key| !current.0.contains_key(*key))
+        {
+            writeln!(f, "{} part {}: -  (removed)", input, part)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn test_watched_paths() {
+        let root = temp_dir("watch");
+        fs::create_dir_all(root.join("data/examples")).unwrap();
+        for name in [
+            "day_06_2.txt",
+            "day_06_1.txt",
+            "day_16_1.txt",
+            "day_06_1.bak",
+        ] {
+            fs::write(root.join("data/examples").join(name), "").unwrap();
+        }
+
+        assert_eq!(
+            watched_paths(&root, 6).unwrap(),
+            vec![
+                PathBuf::from("src/days/day_06.rs"),
+                PathBuf::from("data/day_06.txt"),
+                PathBuf::from("data/examples/day_06_1.txt"),
+                PathBuf::from("data/examples/day_06_2.txt"),
+            ]
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let root = temp_dir("watch_snapshot");
+        let paths = vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")];
+        fs::write(root.join("a.txt"), "1").unwrap();
+
+        let before = Snapshot::take(&root, &paths);
+        assert!(Snapshot::take(&root, &paths).changed(&before).is_empty());
+
+        fs::write(root.join("b.txt"), "2").unwrap();
+        let after = Snapshot::take(&root, &paths);
+        assert_eq!(after.changed(&before), vec![Path::new("b.txt")]);
+
+        let fewer = Snapshot::take(&root, &paths[..1]);
+        assert_eq!(fewer.changed(&after), vec![Path::new("b.txt")]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut previous = Results::default();
+        previous
+            .extend_from_json(
+                "ex.txt",
+                r#"[{"part": 1, "answer": "CMZ"}, {"part": 2, "answer": 12}]"#,
+            )
+            .unwrap();
+        assert_eq!(
+            Diff(&Results::default(), &previous).to_string(),
+            "ex.txt part 1: CMZ\nex.txt part 2: 12\n"
+        );
+
+        let mut current = Results::default();
+        current
+            .extend_from_json(
+                "ex.txt",
+                r#"[{"part": 1, "answer": "CMZ"}, {"part": 2, "error": "oops"}]"#,
+            )
+            .unwrap();
+        current
+            .extend_from_json("in.txt", r#"[{"part": 1, "answer": 7}]"#)
+            .unwrap();
+        assert_eq!(
+            Diff(&previous, &current).to_string(),
+            "ex.txt part 1: CMZ\n\
+             ex.txt part 2: PANIC: oops  (was 12)\n\
+             in.txt part 1: 7  (new)\n"
+        );
+    }
+}
@@ -1,42 +1,56 @@
-fn part1() -> u32 {
-    let _input = parse_input();
-    0
-}
+use crate::{ParseError, Solution};
 
-fn part2() -> u32 {
-    let _input = parse_input();
-    0
-}
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = N;
 
-fn parse_input() -> Vec<()> {
-    let input = include_str!("../../data/day_01.txt");
+    type Input = Vec<()>;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    peg::parser! {
-        grammar parser() for str {
-            pub(crate) rule input() -> Vec<()>
-                = "TODO" { todo!() }
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        peg::parser! {
+            grammar parser() for str {
+                pub(crate) rule input() -> Vec<()>
+                    = "TODO" { todo!() }
+            }
         }
+
+        Ok(parser::input(input)?)
     }
 
-    parser::input(input).unwrap()
-}
+    fn part1(_input: &Self::Input) -> u32 {
+        0
+    }
 
-fn main() {
-    println!("{}", part1());
-    println!("{}", part2());
+    fn part2(_input: &Self::Input) -> u32 {
+        0
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn input() -> Vec<()> {
+        DayNN::parse(&crate::read_input(DayNN::DAY)).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let input = DayNN::parse(&crate::read_example(DayNN::DAY, 1)).unwrap();
+        assert_eq!(DayNN::part1(&input), 0);
+        assert_eq!(DayNN::part2(&input), 0);
+    }
+
     #[test]
     fn test_part1() {
-        assert_eq!(part1(), 0);
+        assert_eq!(DayNN::part1(&input()), 0);
     }
 
     #[test]
     fn test_part2() {
-        assert_eq!(part2(), 0);
+        assert_eq!(DayNN::part2(&input()), 0);
     }
 }